@@ -0,0 +1,50 @@
+pub fn compute() {
+    let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
+    let node00 =
+        taffy.new_with_children(taffy::style::FlexboxLayout { flex_grow: 1f32, ..Default::default() }, &[]).unwrap();
+    let node01 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(30f32),
+                    height: taffy::style::Dimension::Percent(0.5f32),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node0 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                position_type: taffy::style::PositionType::Absolute,
+                align_items: taffy::style::AlignItems::FlexEnd,
+                position: taffy::geometry::Rect {
+                    start: taffy::style::Dimension::Points(20f32),
+                    end: taffy::style::Dimension::Points(30f32),
+                    top: taffy::style::Dimension::Points(10f32),
+                    bottom: taffy::style::Dimension::Points(40f32),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[node00, node01],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(200f32),
+                    height: taffy::style::Dimension::Points(100f32),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[node0],
+        )
+        .unwrap();
+    taffy.compute_layout(node, taffy::geometry::Size::undefined()).unwrap();
+}
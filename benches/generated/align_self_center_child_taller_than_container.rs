@@ -0,0 +1,33 @@
+pub fn compute() {
+    let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
+    let node0 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                align_self: taffy::style::AlignSelf::Center,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(50f32),
+                    height: taffy::style::Dimension::Points(120f32),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                align_items: taffy::style::AlignItems::FlexStart,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(100f32),
+                    height: taffy::style::Dimension::Points(100f32),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[node0],
+        )
+        .unwrap();
+    taffy.compute_layout(node, taffy::geometry::Size::undefined()).unwrap();
+}
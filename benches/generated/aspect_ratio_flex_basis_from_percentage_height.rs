@@ -0,0 +1,30 @@
+pub fn compute() {
+    let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
+    let node0 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                size: taffy::geometry::Size { height: taffy::style::Dimension::Percent(0.5f32), ..Default::default() },
+                aspect_ratio: Some(2f32),
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node1 =
+        taffy.new_with_children(taffy::style::FlexboxLayout { flex_grow: 1f32, ..Default::default() }, &[]).unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(500f32),
+                    height: taffy::style::Dimension::Points(200f32),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[node0, node1],
+        )
+        .unwrap();
+    taffy.compute_layout(node, taffy::geometry::Size::undefined()).unwrap();
+}
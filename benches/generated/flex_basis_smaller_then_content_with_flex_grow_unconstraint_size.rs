@@ -1,5 +1,6 @@
 pub fn compute() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node00 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
@@ -0,0 +1,51 @@
+pub fn compute() {
+    let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
+    let node0 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                flex_grow: 1f32,
+                flex_shrink: 1f32,
+                flex_basis: taffy::style::Dimension::Points(50f32),
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node1 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                flex_grow: 0f32,
+                flex_shrink: 5f32,
+                flex_basis: taffy::style::Dimension::Points(50f32),
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node2 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                flex_grow: 0f32,
+                flex_shrink: 0f32,
+                flex_basis: taffy::style::Dimension::Points(50f32),
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(300f32),
+                    height: taffy::style::Dimension::Points(10f32),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[node0, node1, node2],
+        )
+        .unwrap();
+    taffy.compute_layout(node, taffy::geometry::Size::undefined()).unwrap();
+}
@@ -0,0 +1,65 @@
+pub fn compute() {
+    let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
+    let node00 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                flex_shrink: 0f32,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(100f32),
+                    height: taffy::style::Dimension::Points(10f32),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node01 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                flex_shrink: 0f32,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(100f32),
+                    height: taffy::style::Dimension::Points(10f32),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node02 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                position_type: taffy::style::PositionType::Absolute,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Percent(0.5f32),
+                    height: taffy::style::Dimension::Points(10f32),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node0 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                max_size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(100f32),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[node00, node01, node02],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout { flex_direction: taffy::style::FlexDirection::Column, ..Default::default() },
+            &[node0],
+        )
+        .unwrap();
+    taffy.compute_layout(node, taffy::geometry::Size::undefined()).unwrap();
+}
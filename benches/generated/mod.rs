@@ -1,3 +1,5 @@
+#![allow(clippy::needless_update)]
+
 use criterion::{criterion_group, criterion_main, Criterion};
 mod absolute_layout_align_items_and_justify_content_center;
 mod absolute_layout_align_items_and_justify_content_center_and_bottom_position;
@@ -8,6 +10,7 @@ mod absolute_layout_align_items_and_justify_content_flex_end;
 mod absolute_layout_align_items_center;
 mod absolute_layout_align_items_center_on_child_only;
 mod absolute_layout_child_order;
+mod absolute_layout_children_within_inset_box;
 mod absolute_layout_in_wrap_reverse_column_container;
 mod absolute_layout_in_wrap_reverse_column_container_flex_end;
 mod absolute_layout_in_wrap_reverse_row_container;
@@ -28,6 +31,7 @@ mod align_flex_start_with_shrinking_children;
 mod align_flex_start_with_shrinking_children_with_stretch;
 mod align_flex_start_with_stretching_children;
 mod align_items_center;
+mod align_items_center_child_taller_than_container;
 mod align_items_center_child_with_margin_bigger_than_parent;
 mod align_items_center_child_without_margin_bigger_than_parent;
 mod align_items_center_with_child_margin;
@@ -38,12 +42,15 @@ mod align_items_flex_end_child_without_margin_bigger_than_parent;
 mod align_items_flex_start;
 mod align_items_min_max;
 mod align_items_stretch;
+mod align_items_stretch_with_definite_cross_size;
 mod align_self_baseline;
 mod align_self_center;
+mod align_self_center_child_taller_than_container;
 mod align_self_flex_end;
 mod align_self_flex_end_override_flex_start;
 mod align_self_flex_start;
 mod align_strech_should_size_based_on_parent;
+mod aspect_ratio_flex_basis_from_percentage_height;
 mod border_center_child;
 mod border_flex_child;
 mod border_no_child;
@@ -80,6 +87,7 @@ mod flex_direction_column_reverse;
 mod flex_direction_row;
 mod flex_direction_row_no_width;
 mod flex_direction_row_reverse;
+mod flex_grow_and_flex_shrink_without_free_space;
 mod flex_grow_child;
 mod flex_grow_flex_basis_percent_min_max;
 mod flex_grow_height_maximized;
@@ -88,6 +96,7 @@ mod flex_grow_less_than_factor_one;
 mod flex_grow_root_minimized;
 mod flex_grow_shrink_at_most;
 mod flex_grow_to_min;
+mod flex_grow_with_positive_free_space_ignores_flex_shrink;
 mod flex_grow_within_constrained_max_column;
 mod flex_grow_within_constrained_max_row;
 mod flex_grow_within_constrained_max_width;
@@ -100,9 +109,14 @@ mod flex_shrink_by_outer_margin_with_max_size;
 mod flex_shrink_flex_grow_child_flex_shrink_other_child;
 mod flex_shrink_flex_grow_row;
 mod flex_shrink_to_zero;
+mod flex_shrink_with_negative_free_space_ignores_flex_grow;
+mod flex_shrink_zero_keeps_the_base_size_overflowing;
+mod flex_shrink_zero_keeps_the_base_size_partly_absorbed;
 mod flex_wrap_align_stretch_fits_one_row;
 mod flex_wrap_children_with_min_main_overriding_flex_basis;
 mod flex_wrap_wrap_to_child_height;
+mod gap_wrap_row_flex_end;
+mod gap_wrap_row_flex_start;
 mod justify_content_column_center;
 mod justify_content_column_flex_end;
 mod justify_content_column_flex_start;
@@ -123,6 +137,7 @@ mod justify_content_row_min_width_and_margin;
 mod justify_content_row_space_around;
 mod justify_content_row_space_between;
 mod justify_content_row_space_evenly;
+mod justify_content_space_between_with_non_growing_items;
 mod margin_and_flex_column;
 mod margin_and_flex_row;
 mod margin_and_stretch_column;
@@ -139,6 +154,7 @@ mod margin_auto_left_child_bigger_than_parent;
 mod margin_auto_left_fix_right_child_bigger_than_parent;
 mod margin_auto_left_right_child_bigger_than_parent;
 mod margin_auto_left_stretching_child;
+mod margin_auto_left_with_justify_content_center;
 mod margin_auto_mutiple_children_column;
 mod margin_auto_mutiple_children_row;
 mod margin_auto_right;
@@ -158,6 +174,8 @@ mod max_height;
 mod max_height_overrides_height;
 mod max_height_overrides_height_on_root;
 mod max_width;
+mod max_width_clamps_auto_width_nested;
+mod max_width_clamps_auto_width_root;
 mod max_width_overrides_width;
 mod max_width_overrides_width_on_root;
 mod min_height;
@@ -169,6 +187,7 @@ mod min_width_overrides_width;
 mod min_width_overrides_width_on_root;
 mod nested_overflowing_child;
 mod nested_overflowing_child_in_constraint_parent;
+mod order_equal_values_keep_source_order;
 mod overflow_cross_axis;
 mod overflow_main_axis;
 mod padding_align_end_child;
@@ -210,6 +229,9 @@ mod rounding_fractial_input_3;
 mod rounding_fractial_input_4;
 mod rounding_total_fractial;
 mod rounding_total_fractial_nested;
+mod row_auto_width_is_the_sum_of_the_items_plain;
+mod row_auto_width_is_the_sum_of_the_items_with_gaps;
+mod row_auto_width_is_the_sum_of_the_items_with_margins;
 mod size_defined_by_child;
 mod size_defined_by_child_with_border;
 mod size_defined_by_child_with_padding;
@@ -230,13 +252,23 @@ mod wrap_reverse_row_align_content_space_around;
 mod wrap_reverse_row_align_content_stretch;
 mod wrap_reverse_row_single_line_different_size;
 mod wrap_row;
+mod wrap_row_align_content_center;
+mod wrap_row_align_content_flex_end;
+mod wrap_row_align_content_flex_start;
+mod wrap_row_align_content_space_around;
+mod wrap_row_align_content_space_between;
+mod wrap_row_align_content_stretch;
 mod wrap_row_align_items_center;
 mod wrap_row_align_items_flex_end;
+mod wrap_row_auto_height_is_the_sum_of_the_lines;
+mod wrap_row_oversized_item_on_its_own_line;
+mod wrap_row_reverse_reverses_items_within_each_line;
 mod wrapped_column_max_height;
 mod wrapped_column_max_height_flex;
 mod wrapped_row_within_align_items_center;
 mod wrapped_row_within_align_items_flex_end;
 mod wrapped_row_within_align_items_flex_start;
+mod zero_size_container_with_overflowing_centered_children;
 fn benchmark(c: &mut Criterion) {
     c.bench_function("generated benchmarks", |b| {
         b.iter(|| {
@@ -249,6 +281,7 @@ fn benchmark(c: &mut Criterion) {
             absolute_layout_align_items_center::compute();
             absolute_layout_align_items_center_on_child_only::compute();
             absolute_layout_child_order::compute();
+            absolute_layout_children_within_inset_box::compute();
             absolute_layout_in_wrap_reverse_column_container::compute();
             absolute_layout_in_wrap_reverse_column_container_flex_end::compute();
             absolute_layout_in_wrap_reverse_row_container::compute();
@@ -269,6 +302,7 @@ fn benchmark(c: &mut Criterion) {
             align_flex_start_with_shrinking_children_with_stretch::compute();
             align_flex_start_with_stretching_children::compute();
             align_items_center::compute();
+            align_items_center_child_taller_than_container::compute();
             align_items_center_child_with_margin_bigger_than_parent::compute();
             align_items_center_child_without_margin_bigger_than_parent::compute();
             align_items_center_with_child_margin::compute();
@@ -279,12 +313,15 @@ fn benchmark(c: &mut Criterion) {
             align_items_flex_start::compute();
             align_items_min_max::compute();
             align_items_stretch::compute();
+            align_items_stretch_with_definite_cross_size::compute();
             align_self_baseline::compute();
             align_self_center::compute();
+            align_self_center_child_taller_than_container::compute();
             align_self_flex_end::compute();
             align_self_flex_end_override_flex_start::compute();
             align_self_flex_start::compute();
             align_strech_should_size_based_on_parent::compute();
+            aspect_ratio_flex_basis_from_percentage_height::compute();
             border_center_child::compute();
             border_flex_child::compute();
             border_no_child::compute();
@@ -321,6 +358,7 @@ fn benchmark(c: &mut Criterion) {
             flex_direction_row::compute();
             flex_direction_row_no_width::compute();
             flex_direction_row_reverse::compute();
+            flex_grow_and_flex_shrink_without_free_space::compute();
             flex_grow_child::compute();
             flex_grow_flex_basis_percent_min_max::compute();
             flex_grow_height_maximized::compute();
@@ -329,6 +367,7 @@ fn benchmark(c: &mut Criterion) {
             flex_grow_root_minimized::compute();
             flex_grow_shrink_at_most::compute();
             flex_grow_to_min::compute();
+            flex_grow_with_positive_free_space_ignores_flex_shrink::compute();
             flex_grow_within_constrained_max_column::compute();
             flex_grow_within_constrained_max_row::compute();
             flex_grow_within_constrained_max_width::compute();
@@ -341,9 +380,14 @@ fn benchmark(c: &mut Criterion) {
             flex_shrink_flex_grow_child_flex_shrink_other_child::compute();
             flex_shrink_flex_grow_row::compute();
             flex_shrink_to_zero::compute();
+            flex_shrink_with_negative_free_space_ignores_flex_grow::compute();
+            flex_shrink_zero_keeps_the_base_size_overflowing::compute();
+            flex_shrink_zero_keeps_the_base_size_partly_absorbed::compute();
             flex_wrap_align_stretch_fits_one_row::compute();
             flex_wrap_children_with_min_main_overriding_flex_basis::compute();
             flex_wrap_wrap_to_child_height::compute();
+            gap_wrap_row_flex_end::compute();
+            gap_wrap_row_flex_start::compute();
             justify_content_column_center::compute();
             justify_content_column_flex_end::compute();
             justify_content_column_flex_start::compute();
@@ -364,6 +408,7 @@ fn benchmark(c: &mut Criterion) {
             justify_content_row_space_around::compute();
             justify_content_row_space_between::compute();
             justify_content_row_space_evenly::compute();
+            justify_content_space_between_with_non_growing_items::compute();
             margin_and_flex_column::compute();
             margin_and_flex_row::compute();
             margin_and_stretch_column::compute();
@@ -380,6 +425,7 @@ fn benchmark(c: &mut Criterion) {
             margin_auto_left_fix_right_child_bigger_than_parent::compute();
             margin_auto_left_right_child_bigger_than_parent::compute();
             margin_auto_left_stretching_child::compute();
+            margin_auto_left_with_justify_content_center::compute();
             margin_auto_mutiple_children_column::compute();
             margin_auto_mutiple_children_row::compute();
             margin_auto_right::compute();
@@ -399,6 +445,8 @@ fn benchmark(c: &mut Criterion) {
             max_height_overrides_height::compute();
             max_height_overrides_height_on_root::compute();
             max_width::compute();
+            max_width_clamps_auto_width_nested::compute();
+            max_width_clamps_auto_width_root::compute();
             max_width_overrides_width::compute();
             max_width_overrides_width_on_root::compute();
             min_height::compute();
@@ -410,6 +458,7 @@ fn benchmark(c: &mut Criterion) {
             min_width_overrides_width_on_root::compute();
             nested_overflowing_child::compute();
             nested_overflowing_child_in_constraint_parent::compute();
+            order_equal_values_keep_source_order::compute();
             overflow_cross_axis::compute();
             overflow_main_axis::compute();
             padding_align_end_child::compute();
@@ -451,6 +500,9 @@ fn benchmark(c: &mut Criterion) {
             rounding_fractial_input_4::compute();
             rounding_total_fractial::compute();
             rounding_total_fractial_nested::compute();
+            row_auto_width_is_the_sum_of_the_items_plain::compute();
+            row_auto_width_is_the_sum_of_the_items_with_gaps::compute();
+            row_auto_width_is_the_sum_of_the_items_with_margins::compute();
             size_defined_by_child::compute();
             size_defined_by_child_with_border::compute();
             size_defined_by_child_with_padding::compute();
@@ -471,13 +523,23 @@ fn benchmark(c: &mut Criterion) {
             wrap_reverse_row_align_content_stretch::compute();
             wrap_reverse_row_single_line_different_size::compute();
             wrap_row::compute();
+            wrap_row_align_content_center::compute();
+            wrap_row_align_content_flex_end::compute();
+            wrap_row_align_content_flex_start::compute();
+            wrap_row_align_content_space_around::compute();
+            wrap_row_align_content_space_between::compute();
+            wrap_row_align_content_stretch::compute();
             wrap_row_align_items_center::compute();
             wrap_row_align_items_flex_end::compute();
+            wrap_row_auto_height_is_the_sum_of_the_lines::compute();
+            wrap_row_oversized_item_on_its_own_line::compute();
+            wrap_row_reverse_reverses_items_within_each_line::compute();
             wrapped_column_max_height::compute();
             wrapped_column_max_height_flex::compute();
             wrapped_row_within_align_items_center::compute();
             wrapped_row_within_align_items_flex_end::compute();
             wrapped_row_within_align_items_flex_start::compute();
+            zero_size_container_with_overflowing_centered_children::compute();
         })
     });
 }
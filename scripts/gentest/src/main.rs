@@ -106,6 +106,8 @@ async fn main() {
     }
 
     let bench_mods = quote!(
+        #![allow(clippy::needless_update)]
+
         use criterion::{criterion_group, criterion_main, Criterion};
 
         #test_mods
@@ -149,6 +151,7 @@ fn generate_bench(description: &json::JsonValue) -> TokenStream {
     quote!(
         pub fn compute() {
             let mut taffy = taffy::Taffy::new();
+            taffy.enable_rounding();
             #node_description
             taffy.compute_layout(node, taffy::geometry::Size::undefined()).unwrap();
         }
@@ -165,6 +168,7 @@ fn generate_test(name: impl AsRef<str>, description: &json::JsonValue) -> TokenS
         #[test]
         fn #name() {
             let mut taffy = taffy::Taffy::new();
+            taffy.enable_rounding();
             #node_description
             taffy.compute_layout(node, taffy::geometry::Size::undefined()).unwrap();
             #assertions
@@ -358,6 +362,30 @@ fn generate_node(ident: &str, node: &json::JsonValue) -> TokenStream {
         _ => quote!(),
     };
 
+    let aspect_ratio = match style["aspectRatio"] {
+        json::JsonValue::Number(value) => {
+            let value: f32 = value.into();
+            quote!(aspect_ratio: Some(#value),)
+        }
+        _ => quote!(),
+    };
+
+    let order = match style["order"] {
+        json::JsonValue::Number(value) => {
+            let value = f64::from(value) as i32;
+            quote!(order: #value,)
+        }
+        _ => quote!(),
+    };
+
+    let gap = match style["gap"] {
+        json::JsonValue::Object(ref value) => {
+            let gap = generate_size(value);
+            quote!(gap: #gap,)
+        }
+        _ => quote!(),
+    };
+
     macro_rules! edges_quoted {
         ($style:ident, $val:ident) => {
             let $val = match $style[stringify!($val)] {
@@ -422,6 +450,9 @@ fn generate_node(ident: &str, node: &json::JsonValue) -> TokenStream {
             #padding
             #position
             #border
+            #aspect_ratio
+            #order
+            #gap
             ..Default::default()
         },
         #children
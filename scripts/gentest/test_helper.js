@@ -36,6 +36,14 @@ function parseNumber(input) {
   }
 }
 
+function parseAspectRatio(input) {
+  if (input === "" || input === "auto") {
+    return undefined;
+  }
+  const [width, height = "1"] = input.split("/").map(part => part.trim());
+  return parseNumber(width) / parseNumber(height);
+}
+
 function parseEnum(input) {
   if (input) {
     return input;
@@ -129,6 +137,11 @@ function describeElement(e) {
         top: e.style.top,
         bottom: e.style.bottom,
       }),
+
+      aspectRatio: parseAspectRatio(e.style.aspectRatio),
+      order: parseNumber(e.style.order),
+
+      gap: parseSize({width: e.style.columnGap, height: e.style.rowGap}),
     },
 
     layout: {
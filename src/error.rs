@@ -11,7 +11,7 @@ pub struct InvalidNode(pub Node);
 #[cfg(feature = "std")]
 impl Display for InvalidNode {
     fn fmt(&self, f: &mut Formatter) -> Result {
-        write!(f, "Node {:?} is not in the Taffy instance", self.0)
+        write!(f, "{} is not in the Taffy instance", self.0)
     }
 }
 
@@ -34,24 +34,49 @@ pub enum InvalidChild {
     InvalidParentNode(Node),
     /// The child [`Node`] was not found in the [`Taffy`](crate::Taffy) instance.
     InvalidChildNode(Node),
+    /// The `child` [`Node`] is not a child of the `parent` [`Node`]
+    NotAChild {
+        /// The parent node whose children were searched
+        parent: Node,
+        /// The node that was expected among its children
+        child: Node,
+    },
 }
 
 #[cfg(feature = "std")]
 impl Display for InvalidChild {
     fn fmt(&self, f: &mut Formatter) -> Result {
         match self {
-            InvalidChild::ChildIndexOutOfBounds { parent, child_index, child_count } => write!(
-                f,
-                "Index (is {}) should be < child_count ({}) for parent node {:?}",
-                child_index, child_count, parent
-            ),
+            InvalidChild::ChildIndexOutOfBounds { parent, child_index, child_count } => {
+                write!(f, "Index (is {}) should be < child_count ({}) for parent {}", child_index, child_count, parent)
+            }
             InvalidChild::InvalidParentNode(parent) => {
-                write!(f, "Parent Node {:?} is not in the Taffy instance", parent)
+                write!(f, "Parent {} is not in the Taffy instance", parent)
+            }
+            InvalidChild::InvalidChildNode(child) => write!(f, "Child {} is not in the Taffy instance", child),
+            InvalidChild::NotAChild { parent, child } => {
+                write!(f, "Node {} is not a child of parent {}", child, parent)
             }
-            InvalidChild::InvalidChildNode(child) => write!(f, "Child Node {:?} is not in the Taffy instance", child),
         }
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for InvalidChild {}
+
+/// A string could not be parsed into a style value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidStyleValue {
+    /// The name of the style type that the string was being parsed into
+    pub type_name: &'static str,
+}
+
+#[cfg(feature = "std")]
+impl Display for InvalidStyleValue {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "The string is not a valid {} value", self.type_name)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidStyleValue {}
@@ -3,7 +3,7 @@
 //! Note that some minor steps appear to be missing: see https://github.com/DioxusLabs/taffy/issues for more information.
 use core::f32;
 
-use crate::forest::{Forest, NodeData};
+use crate::forest::{Forest, NodeData, CACHE_SIZE};
 use crate::geometry::{Point, Rect, Size};
 use crate::layout::{Cache, Layout};
 use crate::math::MaybeMath;
@@ -97,6 +97,8 @@ struct AlgoConstants {
 
     /// The size of the internal node
     node_inner_size: Size<Option<f32>>,
+    /// The space between adjacent items (in the main axis) and adjacent lines (in the cross axis)
+    gap: Size<f32>,
     /// The size of the surrounding container
     container_size: Size<f32>,
     /// The size of the internal container
@@ -105,7 +107,16 @@ struct AlgoConstants {
 
 impl Forest {
     /// Computes the layout of this [`Forest`] according to the flexbox algorithm
-    pub(crate) fn compute(&mut self, root: NodeId, size: Size<Option<f32>>) {
+    ///
+    /// The root is placed at `origin`. If `use_rounding` is set, the resulting layouts are snapped to whole pixels.
+    pub(crate) fn compute(&mut self, root: NodeId, size: Size<Option<f32>>, origin: Point<f32>, use_rounding: bool) {
+        #[cfg(test)]
+        {
+            self.compute_count += 1;
+        }
+        #[cfg(feature = "profiling")]
+        self.reset_layout_times();
+
         let style = self.nodes[root].style;
         let has_root_min_max = style.min_size.width.is_defined()
             || style.min_size.height.is_defined()
@@ -113,33 +124,117 @@ impl Forest {
             || style.max_size.height.is_defined();
 
         let preliminary_size = if has_root_min_max {
-            let first_pass = self.compute_preliminary(root, style.size.maybe_resolve(size), size, false, true);
+            let first_pass = self.compute_preliminary(root, style.size.maybe_resolve(size), size, false);
 
             self.compute_preliminary(
                 root,
                 Size {
                     width: first_pass
                         .width
-                        .maybe_max(style.min_size.width.maybe_resolve(size.width))
                         .maybe_min(style.max_size.width.maybe_resolve(size.width))
+                        .maybe_max(style.min_size.width.maybe_resolve(size.width))
                         .into(),
                     height: first_pass
                         .height
-                        .maybe_max(style.min_size.height.maybe_resolve(size.height))
                         .maybe_min(style.max_size.height.maybe_resolve(size.height))
+                        .maybe_max(style.min_size.height.maybe_resolve(size.height))
                         .into(),
                 },
                 size,
                 true,
-                true,
             )
         } else {
-            self.compute_preliminary(root, style.size.maybe_resolve(size), size, true, true)
+            self.compute_preliminary(root, style.size.maybe_resolve(size), size, true)
         };
 
-        self.nodes[root].layout = Layout { order: 0, size: preliminary_size, location: Point::ZERO };
+        self.nodes[root].unresolved_percentage_size = unresolved_percentage_size(style.size, size);
+        self.nodes[root].layout = Layout {
+            order: 0,
+            size: preliminary_size,
+            location: origin,
+            margin: style.margin.resolve_or_default(size.width),
+            border: style.border.resolve_or_default(size.width),
+            padding: style.padding.resolve_or_default(size.width),
+            content_size: self.nodes[root].layout.content_size,
+        };
 
-        Self::round_layout(&mut self.nodes, &self.children, root, 0.0, 0.0);
+        if use_rounding {
+            Self::round_layout(&mut self.nodes, &self.children, root, 0.0, 0.0);
+        }
+    }
+
+    /// Computes the layout of the descendants of `node` as if it were a root of exactly `size`
+    ///
+    /// Unlike [`Forest::compute`], the size of `node` is forced regardless of its style,
+    /// and only its size is updated: its location and order are left for its parent to set.
+    pub(crate) fn compute_subtree(&mut self, node: NodeId, size: Size<f32>, use_rounding: bool) {
+        #[cfg(feature = "profiling")]
+        self.reset_layout_times();
+
+        let node_size = size.map(Some);
+        self.nodes[node].layout.size = self.compute_preliminary(node, node_size, node_size, true);
+        self.nodes[node].unresolved_percentage_size = Size { width: false, height: false };
+
+        if use_rounding {
+            Self::round_layout(&mut self.nodes, &self.children, node, 0.0, 0.0);
+        }
+    }
+
+    /// Measures `node` again with the known dimensions `node_size`, storing the result in the matching cache slot
+    ///
+    /// Results cached for other dimensions would otherwise be reused, so they are set aside while measuring.
+    /// Neither the stored layout nor the dirty flag of `node` are changed.
+    pub(crate) fn remeasure(&mut self, node: NodeId, node_size: Size<Option<f32>>) -> Size<f32> {
+        let mut layout_cache = core::mem::replace(&mut self.nodes[node].layout_cache, [None; CACHE_SIZE]);
+        let final_layout_cache = self.nodes[node].final_layout_cache.take();
+        let is_dirty = self.nodes[node].is_dirty;
+
+        let size = self.compute_preliminary(node, node_size, Size::NONE, false);
+
+        for (cache, new_cache) in layout_cache.iter_mut().zip(self.nodes[node].layout_cache) {
+            if new_cache.is_some() {
+                *cache = new_cache;
+            }
+        }
+        self.nodes[node].layout_cache = layout_cache;
+        self.nodes[node].final_layout_cache = final_layout_cache;
+        self.nodes[node].is_dirty = is_dirty;
+        size
+    }
+
+    /// Returns the min-content and max-content sizes of `node`, computing them only if they aren't already cached
+    ///
+    /// The min-content size is the size of the node when laid out with no available space, so that its content
+    /// wraps and shrinks as much as it can. The max-content size is its size when laid out with unlimited space.
+    /// The cached values are cleared whenever the node is marked as dirty.
+    /// Measure functions are only passed `node_size`, so both sizes are the same for a measured leaf.
+    pub(crate) fn intrinsic_sizes(&mut self, node: NodeId) -> (Size<f32>, Size<f32>) {
+        if let (Some(min_content_size), Some(max_content_size)) =
+            (self.nodes[node].min_content_size, self.nodes[node].max_content_size)
+        {
+            return (min_content_size, max_content_size);
+        }
+
+        // Cached results are matched on the node's own size alone, and would ignore the change in available space,
+        // so the node's cache is set aside while measuring and restored afterwards.
+        // Measuring doesn't update the stored layout either, so the node stays dirty if it was.
+        let layout_cache = self.nodes[node].layout_cache;
+        let final_layout_cache = self.nodes[node].final_layout_cache.take();
+        let is_dirty = self.nodes[node].is_dirty;
+        let node_size = self.nodes[node].style.size.maybe_resolve(Size::NONE);
+
+        self.nodes[node].layout_cache = [None; CACHE_SIZE];
+        let min_content_size =
+            self.compute_preliminary(node, node_size, Size { width: Some(0.0), height: Some(0.0) }, false);
+        self.nodes[node].layout_cache = [None; CACHE_SIZE];
+        let max_content_size = self.compute_preliminary(node, node_size, Size::NONE, false);
+        self.nodes[node].layout_cache = layout_cache;
+        self.nodes[node].final_layout_cache = final_layout_cache;
+        self.nodes[node].is_dirty = is_dirty;
+
+        self.nodes[node].min_content_size = Some(min_content_size);
+        self.nodes[node].max_content_size = Some(max_content_size);
+        (min_content_size, max_content_size)
     }
 
     /// Rounds the calculated [`NodeData`] according to the spec
@@ -153,6 +248,18 @@ impl Forest {
 
         layout.size.width = round(layout.size.width);
         layout.size.height = round(layout.size.height);
+        layout.content_size.width = round(layout.content_size.width);
+        layout.content_size.height = round(layout.content_size.height);
+
+        let round_rect = |rect: Rect<f32>| Rect {
+            start: round(rect.start),
+            end: round(rect.end),
+            top: round(rect.top),
+            bottom: round(rect.bottom),
+        };
+        layout.margin = round_rect(layout.margin);
+        layout.border = round_rect(layout.border);
+        layout.padding = round_rect(layout.padding);
 
         for child in &children[root] {
             Self::round_layout(nodes, children, *child, abs_x, abs_y);
@@ -160,26 +267,32 @@ impl Forest {
     }
 
     /// Saves intermediate results to a [`Cache`]
-    fn cache(&mut self, node: NodeId, main_size: bool) -> &mut Option<Cache> {
-        if main_size {
-            &mut self.nodes[node].main_size_layout_cache
-        } else {
-            &mut self.nodes[node].other_layout_cache
-        }
+    ///
+    /// Results are stored in a separate slot for each constraint mode of `node_size`,
+    /// so that definite and indefinite measurements made during the same pass don't evict each other.
+    fn cache(&mut self, node: NodeId, node_size: Size<Option<f32>>) -> &mut Option<Cache> {
+        let slot = usize::from(node_size.width.is_some()) | usize::from(node_size.height.is_some()) << 1;
+        &mut self.nodes[node].layout_cache[slot]
     }
 
     /// Try to get the computation result from the cache.
+    ///
+    /// Every slot is checked, as a result computed under one constraint mode may satisfy a query made under another,
+    /// and so is the result of the last computation that performed layout.
     #[inline]
     fn compute_from_cache(
-        &mut self,
+        &self,
         node: NodeId,
         node_size: Size<Option<f32>>,
         parent_size: Size<Option<f32>>,
         perform_layout: bool,
-        main_size: bool,
     ) -> Option<Size<f32>> {
-        if let Some(ref cache) = self.cache(node, main_size) {
-            if cache.perform_layout || !perform_layout {
+        // Leaves have no children to lay out, so a result computed without performing layout is just as good
+        let is_leaf = self.children[node].is_empty();
+
+        let node_data = &self.nodes[node];
+        for cache in node_data.layout_cache.iter().chain(core::iter::once(&node_data.final_layout_cache)).flatten() {
+            if cache.perform_layout || !perform_layout || is_leaf {
                 let width_compatible = if let Some(width) = node_size.width {
                     abs(width - cache.size.width) < f32::EPSILON
                 } else {
@@ -233,6 +346,11 @@ impl Forest {
             height: node_size.height.maybe_sub(padding_border.vertical_axis_sum()),
         };
 
+        let gap = Size {
+            width: node.style.gap.width.maybe_resolve(node_inner_size.width).unwrap_or(0.0),
+            height: node.style.gap.height.maybe_resolve(node_inner_size.height).unwrap_or(0.0),
+        };
+
         let container_size = Size::ZERO;
         let inner_container_size = Size::ZERO;
 
@@ -245,6 +363,7 @@ impl Forest {
             border,
             padding_border,
             node_inner_size,
+            gap,
             container_size,
             inner_container_size,
         }
@@ -257,14 +376,18 @@ impl Forest {
     /// - [**Generate anonymous flex items**](https://www.w3.org/TR/css-flexbox-1/#algo-anon-box) as described in [§4 Flex Items](https://www.w3.org/TR/css-flexbox-1/#flex-items).
     #[inline]
     fn generate_anonymous_flex_items(&self, node: NodeId, constants: &AlgoConstants) -> Vec<FlexItem> {
-        self.children[node]
+        let mut flex_items: Vec<FlexItem> = self.children[node]
             .iter()
             .map(|child| (child, &self.nodes[*child].style))
-            .filter(|(_, style)| style.position_type != PositionType::Absolute)
+            .filter(|(child, style)| style.position_type != PositionType::Absolute && !self.nodes[**child].is_portal)
             .filter(|(_, style)| style.display != Display::None)
             .map(|(child, child_style)| FlexItem {
                 node: *child,
-                size: child_style.size.maybe_resolve(constants.node_inner_size),
+                // Percentages are resolved first, so that the aspect ratio can derive the other axis from the result
+                size: child_style
+                    .size
+                    .maybe_resolve(constants.node_inner_size)
+                    .maybe_apply_aspect_ratio(child_style.used_aspect_ratio()),
                 min_size: child_style.min_size.maybe_resolve(constants.node_inner_size),
                 max_size: child_style.max_size.maybe_resolve(constants.node_inner_size),
 
@@ -287,7 +410,53 @@ impl Forest {
                 offset_main: 0.0,
                 offset_cross: 0.0,
             })
-            .collect()
+            .collect();
+
+        // Items are placed in `order`, using an insertion sort to keep it stable without requiring an allocator.
+        // This is linear when the items are already in order, which is by far the most common case.
+        for index in 1..flex_items.len() {
+            let mut position = index;
+            while position > 0
+                && self.nodes[flex_items[position - 1].node].style.order
+                    > self.nodes[flex_items[position].node].style.order
+            {
+                flex_items.swap(position - 1, position);
+                position -= 1;
+            }
+        }
+
+        flex_items
+    }
+
+    /// Resolves the intrinsic size keywords in the min and max sizes of the flex items
+    ///
+    /// [`Dimension::MinContent`] and [`Dimension::MaxContent`] can't be resolved against the container,
+    /// so they use the matching intrinsic size of the item instead.
+    #[inline]
+    fn resolve_intrinsic_min_max_sizes(&mut self, flex_items: &mut [FlexItem]) {
+        fn intrinsic(dimension: Dimension, min_content: f32, max_content: f32) -> Option<f32> {
+            match dimension {
+                Dimension::MinContent => Some(min_content),
+                Dimension::MaxContent => Some(max_content),
+                _ => None,
+            }
+        }
+
+        for child in flex_items {
+            let style = self.nodes[child.node].style;
+            let dimensions = [style.min_size.width, style.min_size.height, style.max_size.width, style.max_size.height];
+            if !dimensions.iter().any(|dimension| matches!(dimension, Dimension::MinContent | Dimension::MaxContent)) {
+                continue;
+            }
+
+            let (min_content, max_content) = self.intrinsic_sizes(child.node);
+            let resolve = |dimension: Size<Dimension>, resolved: Size<Option<f32>>| Size {
+                width: intrinsic(dimension.width, min_content.width, max_content.width).or(resolved.width),
+                height: intrinsic(dimension.height, min_content.height, max_content.height).or(resolved.height),
+            };
+            child.min_size = resolve(style.min_size, child.min_size);
+            child.max_size = resolve(style.max_size, child.max_size);
+        }
     }
 
     /// Determine the available main and cross space for the flex items.
@@ -295,10 +464,10 @@ impl Forest {
     /// # [9.2. Line Length Determination](https://www.w3.org/TR/css-flexbox-1/#line-sizing)
     ///
     /// - [**Determine the available main and cross space for the flex items**](https://www.w3.org/TR/css-flexbox-1/#algo-available).
-    /// For each dimension, if that dimension of the flex container’s content box is a definite size, use that;
-    /// if that dimension of the flex container is being sized under a min or max-content constraint, the available space in that dimension is that constraint;
-    /// otherwise, subtract the flex container’s margin, border, and padding from the space available to the flex container in that dimension and use that value.
-    /// **This might result in an infinite value**.
+    ///   For each dimension, if that dimension of the flex container’s content box is a definite size, use that;
+    ///   if that dimension of the flex container is being sized under a min or max-content constraint, the available space in that dimension is that constraint;
+    ///   otherwise, subtract the flex container’s margin, border, and padding from the space available to the flex container in that dimension and use that value.
+    ///   **This might result in an infinite value**.
     #[inline]
     #[must_use]
     fn determine_available_space(
@@ -342,21 +511,20 @@ impl Forest {
     ///     then the flex base size is calculated from its inner cross size and the flex item’s intrinsic aspect ratio.
     ///
     ///     - C. If the used flex basis is content or depends on its available space, and the flex container is being sized under a min-content
-    ///         or max-content constraint (e.g. when performing automatic table layout \[CSS21\]), size the item under that constraint.
-    ///         The flex base size is the item’s resulting main size.
+    ///       or max-content constraint (e.g. when performing automatic table layout \[CSS21\]), size the item under that constraint.
+    ///       The flex base size is the item’s resulting main size.
     ///
     ///     - E. Otherwise, size the item into the available space using its used flex basis in place of its main size, treating a value of content as max-content.
-    ///         If a cross size is needed to determine the main size (e.g. when the flex item’s main size is in its block axis) and the flex item’s cross size is auto and not definite,
-    ///         in this calculation use fit-content as the flex item’s cross size. The flex base size is the item’s resulting main size.
+    ///       If a cross size is needed to determine the main size (e.g. when the flex item’s main size is in its block axis) and the flex item’s cross size is auto and not definite,
+    ///       in this calculation use fit-content as the flex item’s cross size. The flex base size is the item’s resulting main size.
     ///
-    ///     When determining the flex base size, the item’s min and max main sizes are ignored (no clamping occurs).
-    ///     Furthermore, the sizing calculations that floor the content box size at zero when applying box-sizing are also ignored.
-    ///     (For example, an item with a specified size of zero, positive padding, and box-sizing: border-box will have an outer flex base size of zero—and hence a negative inner flex base size.)
+    ///   When determining the flex base size, the item’s min and max main sizes are ignored (no clamping occurs).
+    ///   Furthermore, the sizing calculations that floor the content box size at zero when applying box-sizing are also ignored.
+    ///   (For example, an item with a specified size of zero, positive padding, and box-sizing: border-box will have an outer flex base size of zero—and hence a negative inner flex base size.)
     #[inline]
     fn determine_flex_base_size(
         &mut self,
         node: NodeId,
-        node_size: Size<Option<f32>>,
         constants: &AlgoConstants,
         available_space: Size<Option<f32>>,
         flex_items: &mut Vec<FlexItem>,
@@ -373,15 +541,50 @@ impl Forest {
                 continue;
             };
 
+            //    Intrinsic size keywords (from CSS Sizing) use the matching intrinsic main size of the item instead.
+
+            if let Dimension::MinContent | Dimension::MaxContent = child_style.flex_basis {
+                let (min_content_size, max_content_size) = self.intrinsic_sizes(child.node);
+                let content_size =
+                    if child_style.flex_basis == Dimension::MinContent { min_content_size } else { max_content_size };
+                child.flex_basis = content_size.main(constants.dir);
+                continue;
+            }
+
             // B. If the flex item has an intrinsic aspect ratio,
             //    a used flex basis of content, and a definite cross size,
             //    then the flex base size is calculated from its inner
             //    cross size and the flex item’s intrinsic aspect ratio.
 
-            if let Some(ratio) = child_style.aspect_ratio {
-                if let Some(cross) = node_size.cross(constants.dir) {
-                    if child_style.flex_basis == Dimension::Auto {
-                        child.flex_basis = cross * ratio;
+            //    The cross size is first clamped by the item's own cross min and max sizes. If these conflict,
+            //    the min size wins. The main axis min and max sizes are applied afterwards, when the
+            //    hypothetical main size is determined, and so take precedence over the aspect ratio.
+            //
+            //    An item with a definite cross size of its own has already had the ratio applied to its size,
+            //    so this covers stretched items: in a single-line container with a definite cross size,
+            //    their outer cross size is the container's inner cross size, and is considered definite.
+
+            if let Some(ratio) = child_style.used_aspect_ratio() {
+                let parent_style = &self.nodes[node].style;
+                let is_stretched = parent_style.flex_wrap == FlexWrap::NoWrap
+                    && child_style.align_self(parent_style) == AlignSelf::Stretch
+                    && child_style.cross_margin_start(constants.dir) != Dimension::Auto
+                    && child_style.cross_margin_end(constants.dir) != Dimension::Auto;
+                let stretched_cross = constants
+                    .node_inner_size
+                    .cross(constants.dir)
+                    .maybe_sub(child.margin.cross_axis_sum(constants.dir))
+                    .filter(|_| is_stretched);
+
+                if let Some(cross) = child.size.cross(constants.dir).or(stretched_cross) {
+                    // An item with a definite main size uses that as its flex basis instead
+                    if matches!(child_style.flex_basis, Dimension::Auto | Dimension::Undefined)
+                        && child.size.main(constants.dir).is_none()
+                    {
+                        let cross = cross
+                            .maybe_min(child.max_size.cross(constants.dir))
+                            .maybe_max(child.min_size.cross(constants.dir));
+                        child.flex_basis = if constants.is_row { cross * ratio } else { cross / ratio };
                         continue;
                     }
                 }
@@ -437,7 +640,6 @@ impl Forest {
                     },
                     available_space,
                     false,
-                    true,
                 )
                 .main(constants.dir)
                 .maybe_min(child.max_size.main(constants.dir));
@@ -455,16 +657,22 @@ impl Forest {
             // The following logic was developed not from the spec but by trail and error looking into how
             // webkit handled various scenarios. Can probably be solved better by passing in
             // min-content max-content constraints from the top
-            let min_main = self
-                .compute_preliminary(child.node, Size::undefined(), available_space, false, false)
-                .main(constants.dir)
-                .maybe_max(child.min_size.main(constants.dir))
-                .maybe_min(child.size.main(constants.dir))
-                .into();
+            // The content-based automatic minimum size only applies to items whose min size is `auto` or not given
+            let min_main = if matches!(
+                self.nodes[child.node].style.min_main_size(constants.dir),
+                Dimension::Auto | Dimension::Undefined
+            ) {
+                self.compute_preliminary(child.node, Size::undefined(), available_space, false)
+                    .main(constants.dir)
+                    .maybe_min(child.size.main(constants.dir))
+                    .into()
+            } else {
+                child.min_size.main(constants.dir)
+            };
 
             child.hypothetical_inner_size.set_main(
                 constants.dir,
-                child.flex_basis.maybe_max(min_main).maybe_min(child.max_size.main(constants.dir)),
+                child.flex_basis.maybe_min(child.max_size.main(constants.dir)).maybe_max(min_main),
             );
 
             child.hypothetical_outer_size.set_main(
@@ -483,14 +691,16 @@ impl Forest {
     ///     - If the flex container is single-line, collect all the flex items into a single flex line.
     ///
     ///     - Otherwise, starting from the first uncollected item, collect consecutive items one by one until the first time that the next collected item would not fit into the flex container’s inner main size
-    ///         (or until a forced break is encountered, see [§10 Fragmenting Flex Layout](https://www.w3.org/TR/css-flexbox-1/#pagination)).
-    ///         If the very first uncollected item wouldn’t fit, collect just it into the line.
+    ///       (or until a forced break is encountered, see [§10 Fragmenting Flex Layout](https://www.w3.org/TR/css-flexbox-1/#pagination)).
+    ///       If the very first uncollected item wouldn’t fit, collect just it into the line.
     ///
     ///         For this step, the size of a flex item is its outer hypothetical main size. (**Note: This can be negative**.)
     ///
     ///         Repeat until all flex items have been collected into flex lines.
     ///
     ///         **Note that the "collect as many" line will collect zero-sized flex items onto the end of the previous line even if the last non-zero item exactly "filled up" the line**.
+    ///
+    /// The [`FlexboxLayout::gap`] before each item but the first counts towards the length of its line.
     #[inline]
     fn collect_flex_lines<'a>(
         &self,
@@ -512,7 +722,8 @@ impl Forest {
                     .iter()
                     .enumerate()
                     .find(|&(idx, child)| {
-                        line_length += child.hypothetical_outer_size.main(constants.dir);
+                        let gap = if idx == 0 { 0.0 } else { constants.gap.main(constants.dir) };
+                        line_length += gap + child.hypothetical_outer_size.main(constants.dir);
                         if let Some(main) = available_space.main(constants.dir) {
                             line_length > main && idx != 0
                         } else {
@@ -531,6 +742,27 @@ impl Forest {
         lines
     }
 
+    /// Collapses the main axis margins between adjacent items of a line, so that they are spaced by the larger margin
+    ///
+    /// The margin at the start of each item is reduced by the overlap with the margin at the end of the item before it.
+    /// Negative margins are not collapsed.
+    #[inline]
+    fn collapse_adjacent_margins(line: &mut FlexLine, constants: &AlgoConstants) {
+        let is_reverse = constants.dir.is_reverse();
+
+        for index in 1..line.items.len() {
+            let previous = &line.items[index - 1];
+            let previous_margin = if is_reverse { previous.margin.top } else { previous.margin.bottom };
+
+            let item = &mut line.items[index];
+            let margin = if is_reverse { &mut item.margin.bottom } else { &mut item.margin.top };
+            let overlap = previous_margin.min(*margin).max(0.0);
+
+            *margin -= overlap;
+            item.hypothetical_outer_size.height -= overlap;
+        }
+    }
+
     /// Resolve the flexible lengths of the items within a flex line.
     ///
     /// # [9.7. Resolving Flexible Lengths](https://www.w3.org/TR/css-flexbox-1/#resolve-flexible-lengths)
@@ -546,8 +778,9 @@ impl Forest {
         //    use the flex grow factor for the rest of this algorithm; otherwise, use the
         //    flex shrink factor.
 
+        let main_gaps = sum_gaps(constants.gap.main(constants.dir), line.items.len());
         let used_flex_factor: f32 =
-            line.items.iter().map(|child| child.hypothetical_outer_size.main(constants.dir)).sum();
+            main_gaps + line.items.iter().map(|child| child.hypothetical_outer_size.main(constants.dir)).sum::<f32>();
         let growing = used_flex_factor < constants.node_inner_size.main(constants.dir).unwrap_or(0.0);
         let shrinking = !growing;
 
@@ -567,16 +800,15 @@ impl Forest {
                     self.compute_preliminary(
                         child.node,
                         Size {
-                            width: child.size.width.maybe_max(child.min_size.width).maybe_min(child.max_size.width),
-                            height: child.size.height.maybe_max(child.min_size.height).maybe_min(child.max_size.height),
+                            width: child.size.width.maybe_min(child.max_size.width).maybe_max(child.min_size.width),
+                            height: child.size.height.maybe_min(child.max_size.height).maybe_max(child.min_size.height),
                         },
                         available_space,
                         false,
-                        false,
                     )
                     .main(constants.dir)
-                    .maybe_max(child.min_size.main(constants.dir))
-                    .maybe_min(child.max_size.main(constants.dir)),
+                    .maybe_min(child.max_size.main(constants.dir))
+                    .maybe_max(child.min_size.main(constants.dir)),
                 );
             } else {
                 child.target_size.set_main(constants.dir, child.hypothetical_inner_size.main(constants.dir));
@@ -603,20 +835,26 @@ impl Forest {
         //    and subtract this from the flex container’s inner main size. For frozen items,
         //    use their outer target main size; for other items, use their outer flex base size.
 
-        let used_space: f32 = line
-            .items
-            .iter()
-            .map(|child| {
-                child.margin.main_axis_sum(constants.dir)
-                    + if child.frozen { child.target_size.main(constants.dir) } else { child.flex_basis }
-            })
-            .sum();
+        let used_space: f32 = main_gaps
+            + line
+                .items
+                .iter()
+                .map(|child| {
+                    child.margin.main_axis_sum(constants.dir)
+                        + if child.frozen { child.target_size.main(constants.dir) } else { child.flex_basis }
+                })
+                .sum::<f32>();
 
         let initial_free_space = constants.node_inner_size.main(constants.dir).maybe_sub(used_space).unwrap_or(0.0);
 
         // 4. Loop
+        //
+        //    Every pass freezes at least one item (step e freezes all of them when there is no violation,
+        //    and otherwise every item whose violation has the sign of the total), so the loop runs at most
+        //    once per item before they are all frozen. The loop is bounded all the same, so that a bug
+        //    cannot hang the layout; debug builds report it instead.
 
-        loop {
+        for pass in 0..=line.items.len() {
             // a. Check for flexible items. If all the flex items on the line are frozen,
             //    free space has been distributed; exit this loop.
 
@@ -624,20 +862,23 @@ impl Forest {
                 break;
             }
 
+            debug_assert!(pass < line.items.len(), "flexible lengths did not converge after {} passes", pass);
+
             // b. Calculate the remaining free space as for initial free space, above.
             //    If the sum of the unfrozen flex items’ flex factors is less than one,
             //    multiply the initial free space by this sum. If the magnitude of this
             //    value is less than the magnitude of the remaining free space, use this
             //    as the remaining free space.
 
-            let used_space: f32 = line
-                .items
-                .iter()
-                .map(|child| {
-                    child.margin.main_axis_sum(constants.dir)
-                        + if child.frozen { child.target_size.main(constants.dir) } else { child.flex_basis }
-                })
-                .sum();
+            let used_space: f32 = main_gaps
+                + line
+                    .items
+                    .iter()
+                    .map(|child| {
+                        child.margin.main_axis_sum(constants.dir)
+                            + if child.frozen { child.target_size.main(constants.dir) } else { child.flex_basis }
+                    })
+                    .sum::<f32>();
 
             let mut unfrozen: Vec<&mut FlexItem> = line.items.iter_mut().filter(|child| !child.frozen).collect();
 
@@ -714,11 +955,15 @@ impl Forest {
                 // webkit handled various scenarios. Can probably be solved better by passing in
                 // min-content max-content constraints from the top. Need to figure out correct thing to do here as
                 // just piling on more conditionals.
-                let min_main = if constants.is_row && self.nodes[child.node].measure.is_none() {
-                    self.compute_preliminary(child.node, Size::undefined(), available_space, false, false)
+                // The content-based automatic minimum size only applies to items whose min size is `auto` or not
+                // given: an explicit min size, even zero, replaces it.
+                let min_main = if constants.is_row
+                    && self.nodes[child.node].measure.is_none()
+                    && matches!(self.nodes[child.node].style.min_size.width, Dimension::Auto | Dimension::Undefined)
+                {
+                    self.compute_preliminary(child.node, Size::undefined(), available_space, false)
                         .width
                         .maybe_min(child.size.width)
-                        .maybe_max(child.min_size.width)
                         .into()
                 } else {
                     child.min_size.main(constants.dir)
@@ -762,7 +1007,7 @@ impl Forest {
     /// # [9.4. Cross Size Determination](https://www.w3.org/TR/css-flexbox-1/#cross-sizing)
     ///
     /// - [**Determine the hypothetical cross size of each item**](https://www.w3.org/TR/css-flexbox-1/#algo-cross-item)
-    ///     by performing layout with the used main size and the available space, treating auto as fit-content.
+    ///   by performing layout with the used main size and the available space, treating auto as fit-content.
     #[inline]
     fn determine_hypothetical_cross_size(
         &mut self,
@@ -774,8 +1019,8 @@ impl Forest {
             let child_cross = child
                 .size
                 .cross(constants.dir)
-                .maybe_max(child.min_size.cross(constants.dir))
-                .maybe_min(child.max_size.cross(constants.dir));
+                .maybe_min(child.max_size.cross(constants.dir))
+                .maybe_max(child.min_size.cross(constants.dir));
 
             child.hypothetical_inner_size.set_cross(
                 constants.dir,
@@ -798,11 +1043,10 @@ impl Forest {
                         },
                     },
                     false,
-                    false,
                 )
                 .cross(constants.dir)
-                .maybe_max(child.min_size.cross(constants.dir))
-                .maybe_min(child.max_size.cross(constants.dir)),
+                .maybe_min(child.max_size.cross(constants.dir))
+                .maybe_max(child.min_size.cross(constants.dir)),
             );
 
             child.hypothetical_outer_size.set_cross(
@@ -856,7 +1100,6 @@ impl Forest {
                         },
                     },
                     true,
-                    false,
                 );
 
                 child.baseline = calc_baseline(
@@ -866,6 +1109,10 @@ impl Forest {
                         order: self.children[node].iter().position(|n| *n == child.node).unwrap() as u32,
                         size: preliminary_size,
                         location: Point::ZERO,
+                        margin: child.margin,
+                        border: child.border,
+                        padding: child.padding,
+                        content_size: Size::ZERO,
                     },
                 );
             }
@@ -883,15 +1130,17 @@ impl Forest {
     ///     Otherwise, for each flex line:
     ///
     ///     1. Collect all the flex items whose inline-axis is parallel to the main-axis, whose align-self is baseline, and whose cross-axis margins are both non-auto.
-    ///         Find the largest of the distances between each item’s baseline and its hypothetical outer cross-start edge,
-    ///         and the largest of the distances between each item’s baseline and its hypothetical outer cross-end edge, and sum these two values.
+    ///        Find the largest of the distances between each item’s baseline and its hypothetical outer cross-start edge,
+    ///        and the largest of the distances between each item’s baseline and its hypothetical outer cross-end edge, and sum these two values.
     ///
     ///     2. Among all the items not collected by the previous step, find the largest outer hypothetical cross size.
     ///
     ///     3. The used cross-size of the flex line is the largest of the numbers found in the previous two steps and zero.
     ///
     ///         If the flex container is single-line, then clamp the line’s cross-size to be within the container’s computed min and max cross sizes.
-    ///         **Note that if CSS 2.1’s definition of min/max-width/height applied more generally, this behavior would fall out automatically**.
+    ///        **Note that if CSS 2.1’s definition of min/max-width/height applied more generally, this behavior would fall out automatically**.
+    ///
+    /// Not part of the spec: each line is then grown to at least the container's [`FlexboxLayout::line_min_cross_size`].
     #[inline]
     fn calculate_cross_size(
         &mut self,
@@ -905,6 +1154,13 @@ impl Forest {
                 (node_size.cross(constants.dir).maybe_sub(constants.padding_border.cross_axis_sum(constants.dir)))
                     .unwrap_or(0.0);
         } else {
+            let line_min_cross_size = self.nodes[node]
+                .style
+                .line_min_cross_size
+                .maybe_resolve(constants.node_inner_size.cross(constants.dir))
+                .unwrap_or(0.0)
+                .max(0.0);
+
             for line in flex_lines.iter_mut() {
                 //    1. Collect all the flex items whose inline-axis is parallel to the main-axis, whose
                 //       align-self is baseline, and whose cross-axis margins are both non-auto. Find the
@@ -934,7 +1190,7 @@ impl Forest {
                             child.hypothetical_outer_size.cross(constants.dir)
                         }
                     })
-                    .fold(0.0, |acc, x| acc.max(x));
+                    .fold(line_min_cross_size, |acc, x| acc.max(x));
             }
         }
     }
@@ -944,8 +1200,8 @@ impl Forest {
     /// # [9.4. Cross Size Determination](https://www.w3.org/TR/css-flexbox-1/#cross-sizing)
     ///
     /// - [**Handle 'align-content: stretch'**](https://www.w3.org/TR/css-flexbox-1/#algo-line-stretch). If the flex container has a definite cross size, align-content is stretch,
-    ///     and the sum of the flex lines' cross sizes is less than the flex container’s inner cross size,
-    ///     increase the cross size of each flex line by equal amounts such that the sum of their cross sizes exactly equals the flex container’s inner cross size.
+    ///   and the sum of the flex lines' cross sizes is less than the flex container’s inner cross size,
+    ///   increase the cross size of each flex line by equal amounts such that the sum of their cross sizes exactly equals the flex container’s inner cross size.
     #[inline]
     fn handle_align_content_stretch(
         &mut self,
@@ -955,7 +1211,8 @@ impl Forest {
         constants: &AlgoConstants,
     ) {
         if self.nodes[node].style.align_content == AlignContent::Stretch && node_size.cross(constants.dir).is_some() {
-            let total_cross: f32 = flex_lines.iter().map(|line| line.cross_size).sum();
+            let total_cross: f32 = sum_gaps(constants.gap.cross(constants.dir), flex_lines.len())
+                + flex_lines.iter().map(|line| line.cross_size).sum::<f32>();
             let inner_cross =
                 (node_size.cross(constants.dir).maybe_sub(constants.padding_border.cross_axis_sum(constants.dir)))
                     .unwrap_or(0.0);
@@ -973,8 +1230,8 @@ impl Forest {
     /// # [9.4. Cross Size Determination](https://www.w3.org/TR/css-flexbox-1/#cross-sizing)
     ///
     /// - [**Determine the used cross size of each flex item**](https://www.w3.org/TR/css-flexbox-1/#algo-stretch). If a flex item has align-self: stretch, its computed cross size property is auto,
-    ///     and neither of its cross-axis margins are auto, the used outer cross size is the used cross size of its flex line, clamped according to the item’s used min and max cross sizes.
-    ///     Otherwise, the used cross size is the item’s hypothetical cross size.
+    ///   and neither of its cross-axis margins are auto, the used outer cross size is the used cross size of its flex line, clamped according to the item’s used min and max cross sizes.
+    ///   Otherwise, the used cross size is the item’s hypothetical cross size.
     ///
     ///     If the flex item has align-self: stretch, redo layout for its contents, treating this used size as its definite cross size so that percentage-sized children can be resolved.
     ///
@@ -994,8 +1251,8 @@ impl Forest {
                         && child_style.cross_size(constants.dir) == Dimension::Auto
                     {
                         (line_cross_size - child.margin.cross_axis_sum(constants.dir))
-                            .maybe_max(child.min_size.cross(constants.dir))
                             .maybe_min(child.max_size.cross(constants.dir))
+                            .maybe_max(child.min_size.cross(constants.dir))
                     } else {
                         child.hypothetical_inner_size.cross(constants.dir)
                     },
@@ -1016,7 +1273,7 @@ impl Forest {
     /// - [**Distribute any remaining free space**](https://www.w3.org/TR/css-flexbox-1/#algo-main-align). For each flex line:
     ///
     ///     1. If the remaining free space is positive and at least one main-axis margin on this line is `auto`, distribute the free space equally among these margins.
-    ///         Otherwise, set all `auto` margins to zero.
+    ///        Otherwise, set all `auto` margins to zero.
     ///
     ///     2. Align the items along the main-axis per `justify-content`.
     #[inline]
@@ -1027,7 +1284,8 @@ impl Forest {
         constants: &AlgoConstants,
     ) {
         for line in flex_lines {
-            let used_space: f32 = line.items.iter().map(|child| child.outer_target_size.main(constants.dir)).sum();
+            let used_space: f32 = sum_gaps(constants.gap.main(constants.dir), line.items.len())
+                + line.items.iter().map(|child| child.outer_target_size.main(constants.dir)).sum::<f32>();
             let free_space = constants.inner_container_size.main(constants.dir) - used_space;
             let mut num_auto_margins = 0;
 
@@ -1092,7 +1350,12 @@ impl Forest {
                         }
                         JustifyContent::SpaceBetween => {
                             if is_first {
-                                0.0
+                                // A single item has nothing to be spaced between, so it is placed as for `FlexStart`
+                                if layout_reverse && num_items == 1 {
+                                    free_space
+                                } else {
+                                    0.0
+                                }
                             } else {
                                 free_space / (num_items - 1) as f32
                             }
@@ -1122,13 +1385,13 @@ impl Forest {
     /// # [9.6. Cross-Axis Alignment](https://www.w3.org/TR/css-flexbox-1/#cross-alignment)
     ///
     /// - [**Resolve cross-axis `auto` margins**](https://www.w3.org/TR/css-flexbox-1/#algo-cross-margins).
-    ///     If a flex item has auto cross-axis margins:
+    ///   If a flex item has auto cross-axis margins:
     ///
     ///     - If its outer cross size (treating those auto margins as zero) is less than the cross size of its flex line,
-    ///         distribute the difference in those sizes equally to the auto margins.
+    ///       distribute the difference in those sizes equally to the auto margins.
     ///
     ///     - Otherwise, if the block-start or inline-start margin (whichever is in the cross axis) is auto, set it to zero.
-    ///         Set the opposite margin so that the outer cross size of the item equals the cross size of its flex line.
+    ///       Set the opposite margin so that the outer cross size of the item equals the cross size of its flex line.
     #[inline]
     fn resolve_cross_axis_auto_margins(
         &mut self,
@@ -1186,7 +1449,7 @@ impl Forest {
     /// # [9.6. Cross-Axis Alignment](https://www.w3.org/TR/css-flexbox-1/#cross-alignment)
     ///
     /// - [**Align all flex items along the cross-axis**](https://www.w3.org/TR/css-flexbox-1/#algo-cross-align) per `align-self`,
-    ///     if neither of the item's cross-axis margins are `auto`.
+    ///   if neither of the item's cross-axis margins are `auto`.
     #[inline]
     fn align_flex_items_along_cross_axis(
         &self,
@@ -1253,7 +1516,8 @@ impl Forest {
         node_size: Size<Option<f32>>,
         constants: &mut AlgoConstants,
     ) -> f32 {
-        let total_cross_size: f32 = flex_lines.iter().map(|line| line.cross_size).sum();
+        let total_cross_size: f32 = sum_gaps(constants.gap.cross(constants.dir), flex_lines.len())
+            + flex_lines.iter().map(|line| line.cross_size).sum::<f32>();
 
         constants.container_size.set_cross(
             constants.dir,
@@ -1341,7 +1605,7 @@ impl Forest {
     fn final_layout_pass(&mut self, node: NodeId, flex_lines: &mut [FlexLine], constants: &AlgoConstants) {
         let mut total_offset_cross = constants.padding_border.cross_start(constants.dir);
 
-        let layout_line = |line: &mut FlexLine| {
+        let layout_line = |(line_index, line): (usize, &mut FlexLine)| {
             let mut total_offset_main = constants.padding_border.main_start(constants.dir);
             let line_offset_cross = line.offset_cross;
 
@@ -1351,7 +1615,6 @@ impl Forest {
                     child.target_size.map(|s| s.into()),
                     constants.container_size.map(|s| s.into()),
                     true,
-                    false,
                 );
 
                 let offset_main = total_offset_main
@@ -1367,6 +1630,9 @@ impl Forest {
                     + (child.position.cross_start(constants.dir).unwrap_or(0.0)
                         - child.position.cross_end(constants.dir).unwrap_or(0.0));
 
+                self.nodes[child.node].flex_line = Some(line_index);
+                self.nodes[child.node].unresolved_percentage_size =
+                    unresolved_percentage_size(self.nodes[child.node].style.size, constants.node_inner_size);
                 self.nodes[child.node].layout = Layout {
                     order: self.children[node].iter().position(|n| *n == child.node).unwrap() as u32,
                     size: preliminary_size,
@@ -1374,11 +1640,16 @@ impl Forest {
                         x: if constants.is_row { offset_main } else { offset_cross },
                         y: if constants.is_column { offset_main } else { offset_cross },
                     },
+                    margin: child.margin,
+                    border: child.border,
+                    padding: child.padding,
+                    content_size: self.nodes[child.node].layout.content_size,
                 };
 
                 total_offset_main += child.offset_main
                     + child.margin.main_axis_sum(constants.dir)
-                    + preliminary_size.main(constants.dir);
+                    + preliminary_size.main(constants.dir)
+                    + constants.gap.main(constants.dir);
             };
 
             if constants.dir.is_reverse() {
@@ -1387,13 +1658,13 @@ impl Forest {
                 line.items.iter_mut().for_each(layout_item);
             }
 
-            total_offset_cross += line_offset_cross + line.cross_size;
+            total_offset_cross += line_offset_cross + line.cross_size + constants.gap.cross(constants.dir);
         };
 
         if constants.is_wrap_reverse {
-            flex_lines.iter_mut().rev().for_each(layout_line);
+            flex_lines.iter_mut().enumerate().rev().for_each(layout_line);
         } else {
-            flex_lines.iter_mut().for_each(layout_line);
+            flex_lines.iter_mut().enumerate().for_each(layout_line);
         }
     }
 
@@ -1405,12 +1676,15 @@ impl Forest {
             .iter()
             .cloned()
             .enumerate()
-            .filter(|(_, child)| self.nodes[*child].style.position_type == PositionType::Absolute)
+            .filter(|(_, child)| {
+                self.nodes[*child].style.position_type == PositionType::Absolute || self.nodes[*child].is_portal
+            })
             .collect::<Vec<_>>();
 
         for (order, child) in candidates {
-            let container_width = constants.container_size.width.into();
-            let container_height = constants.container_size.height.into();
+            // Absolutely positioned children are sized and positioned against the padding box of their container
+            let container_width = Some(constants.container_size.width - constants.border.horizontal_axis_sum());
+            let container_height = Some(constants.container_size.height - constants.border.vertical_axis_sum());
 
             let child_style = self.nodes[child].style;
 
@@ -1439,8 +1713,8 @@ impl Forest {
                 .size
                 .width
                 .maybe_resolve(container_width)
-                .maybe_max(child_style.min_size.width.maybe_resolve(container_width))
-                .maybe_min(child_style.max_size.width.maybe_resolve(container_width));
+                .maybe_min(child_style.max_size.width.maybe_resolve(container_width))
+                .maybe_max(child_style.min_size.width.maybe_resolve(container_width));
 
             if width.is_none() && start.is_some() && end.is_some() {
                 width = container_width.maybe_sub(start).maybe_sub(end);
@@ -1450,8 +1724,8 @@ impl Forest {
                 .size
                 .height
                 .maybe_resolve(container_height)
-                .maybe_max(child_style.min_size.height.maybe_resolve(container_height))
-                .maybe_min(child_style.max_size.height.maybe_resolve(container_height));
+                .maybe_min(child_style.max_size.height.maybe_resolve(container_height))
+                .maybe_max(child_style.min_size.height.maybe_resolve(container_height));
 
             if height.is_none() && top.is_some() && bottom.is_some() {
                 height = container_height.maybe_sub(top).maybe_sub(bottom);
@@ -1462,34 +1736,33 @@ impl Forest {
                 Size { width, height },
                 Size { width: container_width, height: container_height },
                 true,
-                false,
             );
 
             let free_main_space = constants.container_size.main(constants.dir)
                 - preliminary_size
                     .main(constants.dir)
-                    .maybe_max(
+                    .maybe_min(
                         child_style
-                            .min_main_size(constants.dir)
+                            .max_main_size(constants.dir)
                             .maybe_resolve(constants.node_inner_size.main(constants.dir)),
                     )
-                    .maybe_min(
+                    .maybe_max(
                         child_style
-                            .max_main_size(constants.dir)
+                            .min_main_size(constants.dir)
                             .maybe_resolve(constants.node_inner_size.main(constants.dir)),
                     );
 
             let free_cross_space = constants.container_size.cross(constants.dir)
                 - preliminary_size
                     .cross(constants.dir)
-                    .maybe_max(
+                    .maybe_min(
                         child_style
-                            .min_cross_size(constants.dir)
+                            .max_cross_size(constants.dir)
                             .maybe_resolve(constants.node_inner_size.cross(constants.dir)),
                     )
-                    .maybe_min(
+                    .maybe_max(
                         child_style
-                            .max_cross_size(constants.dir)
+                            .min_cross_size(constants.dir)
                             .maybe_resolve(constants.node_inner_size.cross(constants.dir)),
                     );
 
@@ -1542,6 +1815,8 @@ impl Forest {
                 }
             };
 
+            self.nodes[child].flex_line = None;
+            self.nodes[child].unresolved_percentage_size = Size { width: false, height: false };
             self.nodes[child].layout = Layout {
                 order: order as u32,
                 size: preliminary_size,
@@ -1549,23 +1824,79 @@ impl Forest {
                     x: if constants.is_row { offset_main } else { offset_cross },
                     y: if constants.is_column { offset_main } else { offset_cross },
                 },
+                margin: Rect {
+                    start: child_margin_start.unwrap_or(0.0),
+                    end: child_margin_end.unwrap_or(0.0),
+                    top: child_margin_top.unwrap_or(0.0),
+                    bottom: child_margin_bottom.unwrap_or(0.0),
+                },
+                border: child_style.border.resolve_or_default(container_width),
+                padding: child_style.padding.resolve_or_default(container_width),
+                content_size: self.nodes[child].layout.content_size,
             };
         }
     }
 
+    /// The size of the area spanned by the laid out children of `node` and their own content
+    fn content_size(&self, node: NodeId) -> Size<f32> {
+        self.children[node].iter().fold(Size::ZERO, |content_size, child| {
+            let layout = &self.nodes[*child].layout;
+            Size {
+                width: content_size.width.max(layout.location.x + layout.size.width.max(layout.content_size.width)),
+                height: content_size.height.max(layout.location.y + layout.size.height.max(layout.content_size.height)),
+            }
+        })
+    }
+
+    /// Resets the time spent laying out each node, before a new layout is computed
+    #[cfg(feature = "profiling")]
+    fn reset_layout_times(&mut self) {
+        for node in &mut self.nodes {
+            node.layout_time = std::time::Duration::ZERO;
+        }
+    }
+
     /// Compute a preliminary size for an item
+    ///
+    /// With the `profiling` feature, the time this takes is added to the layout time of the node.
+    #[inline]
     fn compute_preliminary(
         &mut self,
         node: NodeId,
         node_size: Size<Option<f32>>,
         parent_size: Size<Option<f32>>,
         perform_layout: bool,
-        main_size: bool,
     ) -> Size<f32> {
+        #[cfg(feature = "profiling")]
+        let start = std::time::Instant::now();
+
+        let size = self.compute_node(node, node_size, parent_size, perform_layout);
+
+        #[cfg(feature = "profiling")]
+        {
+            self.nodes[node].layout_time += start.elapsed();
+        }
+
+        size
+    }
+
+    /// Computes the size of `node` and, if `perform_layout` is set, the layouts of its descendants
+    fn compute_node(
+        &mut self,
+        node: NodeId,
+        node_size: Size<Option<f32>>,
+        parent_size: Size<Option<f32>>,
+        perform_layout: bool,
+    ) -> Size<f32> {
+        // Frozen subtrees keep their previous layout, even if they have since been marked as dirty
+        if self.nodes[node].is_frozen {
+            return self.nodes[node].layout.size;
+        }
+
         self.nodes[node].is_dirty = false;
 
         // First we check if we have a result for the given input
-        if let Some(cached_size) = self.compute_from_cache(node, node_size, parent_size, perform_layout, main_size) {
+        if let Some(cached_size) = self.compute_from_cache(node, node_size, parent_size, perform_layout) {
             return cached_size;
         }
 
@@ -1579,12 +1910,19 @@ impl Forest {
             }
 
             if let Some(ref measure) = self.nodes[node].measure {
-                let converted_size = match measure {
+                let measured_size = match measure {
                     MeasureFunc::Raw(measure) => measure(node_size),
                     #[cfg(any(feature = "std", feature = "alloc"))]
                     MeasureFunc::Boxed(measure) => measure(node_size),
                 };
-                *self.cache(node, main_size) =
+
+                #[cfg(debug_assertions)]
+                if !is_valid_measurement(measured_size) && self.invalid_measurement.is_none() {
+                    self.invalid_measurement = Some((node, measured_size));
+                }
+
+                let converted_size = sanitize_measurement(measured_size);
+                *self.cache(node, node_size) =
                     Some(Cache { node_size, parent_size, perform_layout, size: converted_size });
                 return converted_size;
             }
@@ -1601,6 +1939,7 @@ impl Forest {
 
         // 1. Generate anonymous flex items as described in §4 Flex Items.
         let mut flex_items = self.generate_anonymous_flex_items(node, &constants);
+        self.resolve_intrinsic_min_max_sizes(&mut flex_items);
 
         // 9.2. Line Length Determination
 
@@ -1612,7 +1951,7 @@ impl Forest {
             .any(|child| self.nodes[child.node].style.align_self(&self.nodes[node].style) == AlignSelf::Baseline);
 
         // 3. Determine the flex base size and hypothetical main size of each item.
-        self.determine_flex_base_size(node, node_size, &constants, available_space, &mut flex_items);
+        self.determine_flex_base_size(node, &constants, available_space, &mut flex_items);
 
         // TODO: Add step 4 according to spec: https://www.w3.org/TR/css-flexbox-1/#algo-main-container
         // 9.3. Main Size Determination
@@ -1620,6 +1959,13 @@ impl Forest {
         // 5. Collect flex items into flex lines.
         let mut flex_lines = self.collect_flex_lines(node, &constants, available_space, &mut flex_items);
 
+        // Not part of the spec: an opt-in for block-like stacking of items
+        if self.nodes[node].style.collapse_margins && constants.is_column {
+            for line in &mut flex_lines {
+                Self::collapse_adjacent_margins(line, &constants);
+            }
+        }
+
         // 6. Resolve the flexible lengths of all the flex items to find their used main size.
         for line in &mut flex_lines {
             self.resolve_flexible_lengths(line, &constants, available_space);
@@ -1631,7 +1977,8 @@ impl Forest {
             constants.dir,
             node_size.main(constants.dir).unwrap_or({
                 let longest_line = flex_lines.iter().fold(f32::MIN, |acc, line| {
-                    let length: f32 = line.items.iter().map(|item| item.outer_target_size.main(constants.dir)).sum();
+                    let length: f32 = sum_gaps(constants.gap.main(constants.dir), line.items.len())
+                        + line.items.iter().map(|item| item.outer_target_size.main(constants.dir)).sum::<f32>();
                     acc.max(length)
                 });
 
@@ -1702,7 +2049,7 @@ impl Forest {
         // If our caller does not care about performing layout we are done now.
         if !perform_layout {
             let container_size = constants.container_size;
-            *self.cache(node, main_size) = Some(Cache { node_size, parent_size, perform_layout, size: container_size });
+            *self.cache(node, node_size) = Some(Cache { node_size, parent_size, perform_layout, size: container_size });
             return container_size;
         }
 
@@ -1719,7 +2066,9 @@ impl Forest {
         ///
         /// Each hidden node has zero size and is placed at the origin
         fn hidden_layout(nodes: &mut [NodeData], children: &[ChildrenVec<NodeId>], node: NodeId, order: u32) {
-            nodes[node].layout = Layout { order, size: Size::ZERO, location: Point::ZERO };
+            nodes[node].layout = Layout { order, ..Layout::new() };
+            nodes[node].flex_line = None;
+            nodes[node].unresolved_percentage_size = Size { width: false, height: false };
 
             for (order, child) in children[node].iter().enumerate() {
                 hidden_layout(nodes, children, *child, order as _);
@@ -1732,13 +2081,44 @@ impl Forest {
             }
         }
 
+        self.nodes[node].layout.content_size = self.content_size(node);
+
         let container_size = constants.container_size;
-        *self.cache(node, main_size) = Some(Cache { node_size, parent_size, perform_layout, size: container_size });
+        let cache = Cache { node_size, parent_size, perform_layout, size: container_size };
+        *self.cache(node, node_size) = Some(cache);
+        self.nodes[node].final_layout_cache = Some(cache);
 
         container_size
     }
 }
 
+/// Returns which axes of a percentage `size` can't be resolved, because that axis of `parent_size` is indefinite
+fn unresolved_percentage_size(size: Size<Dimension>, parent_size: Size<Option<f32>>) -> Size<bool> {
+    Size {
+        width: matches!(size.width, Dimension::Percent(_)) && parent_size.width.is_none(),
+        height: matches!(size.height, Dimension::Percent(_)) && parent_size.height.is_none(),
+    }
+}
+
+/// Whether a size returned by a measure function is finite and non-negative
+fn is_valid_measurement(size: Size<f32>) -> bool {
+    size.is_finite() && size.width >= 0.0 && size.height >= 0.0
+}
+
+/// Replaces the invalid axes of a measured size with `0.0`, so that they cannot poison the rest of the layout
+fn sanitize_measurement(size: Size<f32>) -> Size<f32> {
+    if is_valid_measurement(size) {
+        size
+    } else {
+        size.sanitize().map(|value| value.max(0.0))
+    }
+}
+
+/// The total space taken by the gaps between `count` adjacent items or lines
+fn sum_gaps(gap: f32, count: usize) -> f32 {
+    gap * count.saturating_sub(1) as f32
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -1746,7 +2126,7 @@ mod tests {
         math::MaybeMath,
         prelude::{Rect, Size},
         resolve::ResolveOrDefault,
-        style::{FlexWrap, FlexboxLayout},
+        style::{Dimension, FlexDirection, FlexWrap, FlexboxLayout, JustifyContent},
     };
 
     // Make sure we get correct constants
@@ -1795,4 +2175,910 @@ mod tests {
         assert_eq!(constants.container_size, Size::ZERO);
         assert_eq!(constants.inner_container_size, Size::ZERO);
     }
+
+    #[test]
+    fn flex_shrink_is_weighted_by_flex_basis() {
+        use crate::{node::Taffy, style::Dimension};
+
+        let mut taffy = Taffy::new();
+        let small = taffy
+            .new_leaf(FlexboxLayout { flex_basis: Dimension::Points(100.0), flex_shrink: 1.0, ..Default::default() })
+            .unwrap();
+        let large = taffy
+            .new_leaf(FlexboxLayout { flex_basis: Dimension::Points(300.0), flex_shrink: 1.0, ..Default::default() })
+            .unwrap();
+        let container = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    size: Size { width: Dimension::Points(300.0), height: Dimension::Points(100.0) },
+                    ..Default::default()
+                },
+                &[small, large],
+            )
+            .unwrap();
+
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+
+        // 100px of overflow is split 1:3 between the two items
+        assert_eq!(taffy.layout(small).unwrap().size.width, 75.0);
+        assert_eq!(taffy.layout(large).unwrap().size.width, 225.0);
+    }
+
+    #[test]
+    fn absolute_children_are_sized_against_the_padding_box() {
+        use crate::{
+            geometry::Point,
+            node::Taffy,
+            style::{Dimension, PositionType},
+        };
+
+        let mut taffy = Taffy::new();
+        let percent_sized = taffy
+            .new_leaf(FlexboxLayout {
+                position_type: PositionType::Absolute,
+                size: Size { width: Dimension::Percent(1.0), height: Dimension::Percent(0.5) },
+                ..Default::default()
+            })
+            .unwrap();
+        let inset_sized = taffy
+            .new_leaf(FlexboxLayout {
+                position_type: PositionType::Absolute,
+                position: Rect {
+                    start: Dimension::Points(0.0),
+                    end: Dimension::Points(0.0),
+                    top: Dimension::Percent(0.5),
+                    bottom: Dimension::Points(0.0),
+                },
+                ..Default::default()
+            })
+            .unwrap();
+        let container = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    size: Size { width: Dimension::Points(200.0), height: Dimension::Points(100.0) },
+                    padding: Rect::from_points(10.0, 10.0, 10.0, 10.0),
+                    border: Rect::from_points(5.0, 5.0, 5.0, 5.0),
+                    ..Default::default()
+                },
+                &[percent_sized, inset_sized],
+            )
+            .unwrap();
+
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+
+        // The padding box is the 200x100 border box, minus the 5px border on each side
+        let layout = taffy.layout(percent_sized).unwrap();
+        assert_eq!(layout.size, Size { width: 190.0, height: 45.0 });
+        assert_eq!(layout.location, Point { x: 15.0, y: 15.0 });
+
+        let layout = taffy.layout(inset_sized).unwrap();
+        assert_eq!(layout.size, Size { width: 190.0, height: 45.0 });
+        assert_eq!(layout.location, Point { x: 5.0, y: 50.0 });
+    }
+
+    #[test]
+    fn space_freed_by_max_size_violation_is_redistributed() {
+        use crate::{node::Taffy, style::Dimension};
+
+        let mut taffy = Taffy::new();
+        let grow_style = FlexboxLayout { flex_grow: 1.0, flex_basis: Dimension::Points(0.0), ..Default::default() };
+        let capped = taffy
+            .new_leaf(FlexboxLayout {
+                max_size: Size { width: Dimension::Points(50.0), height: Dimension::Undefined },
+                ..grow_style
+            })
+            .unwrap();
+        let first = taffy.new_leaf(grow_style).unwrap();
+        let second = taffy.new_leaf(grow_style).unwrap();
+        let container = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    size: Size { width: Dimension::Points(300.0), height: Dimension::Points(100.0) },
+                    ..Default::default()
+                },
+                &[capped, first, second],
+            )
+            .unwrap();
+
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+
+        // The capped item is frozen at its max size, and the other two share the rest
+        assert_eq!(taffy.layout(capped).unwrap().size.width, 50.0);
+        assert_eq!(taffy.layout(first).unwrap().size.width, 125.0);
+        assert_eq!(taffy.layout(second).unwrap().size.width, 125.0);
+    }
+
+    /// A line with a single item has no space "between" items, so the distributed modes fall back to start or center
+    #[rstest::rstest]
+    #[case(FlexDirection::Row, JustifyContent::FlexStart, 0.0)]
+    #[case(FlexDirection::Row, JustifyContent::FlexEnd, 80.0)]
+    #[case(FlexDirection::Row, JustifyContent::Center, 40.0)]
+    #[case(FlexDirection::Row, JustifyContent::SpaceBetween, 0.0)]
+    #[case(FlexDirection::Row, JustifyContent::SpaceAround, 40.0)]
+    #[case(FlexDirection::Row, JustifyContent::SpaceEvenly, 40.0)]
+    #[case(FlexDirection::RowReverse, JustifyContent::FlexStart, 80.0)]
+    #[case(FlexDirection::RowReverse, JustifyContent::FlexEnd, 0.0)]
+    #[case(FlexDirection::RowReverse, JustifyContent::Center, 40.0)]
+    #[case(FlexDirection::RowReverse, JustifyContent::SpaceBetween, 80.0)]
+    #[case(FlexDirection::RowReverse, JustifyContent::SpaceAround, 40.0)]
+    #[case(FlexDirection::RowReverse, JustifyContent::SpaceEvenly, 40.0)]
+    fn justify_content_with_a_single_item(
+        #[case] flex_direction: FlexDirection,
+        #[case] justify_content: JustifyContent,
+        #[case] expected_x: f32,
+    ) {
+        use crate::{node::Taffy, style::Dimension};
+
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(FlexboxLayout {
+                size: Size { width: Dimension::Points(20.0), height: Dimension::Points(20.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let container = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    flex_direction,
+                    justify_content,
+                    size: Size { width: Dimension::Points(100.0), height: Dimension::Points(20.0) },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().location.x, expected_x);
+    }
+
+    #[test]
+    fn space_around_puts_half_gaps_at_the_edges() {
+        use crate::node::Taffy;
+
+        let mut taffy = Taffy::new();
+        let item_style = FlexboxLayout {
+            size: Size { width: Dimension::Points(60.0), height: Dimension::Points(20.0) },
+            ..Default::default()
+        };
+        let items = [(); 3].map(|_| taffy.new_leaf(item_style).unwrap());
+        let container = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    justify_content: JustifyContent::SpaceAround,
+                    size: Size { width: Dimension::Points(300.0), height: Dimension::Points(20.0) },
+                    ..Default::default()
+                },
+                &items,
+            )
+            .unwrap();
+
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+
+        // 120px of free space: 40px around each item, so 20px at the edges and 40px between items
+        let xs = items.map(|item| taffy.layout(item).unwrap().location.x);
+        assert_eq!(xs, [20.0, 120.0, 220.0]);
+    }
+
+    #[test]
+    fn redistribution_converges_across_staggered_max_sizes() {
+        use crate::{node::Taffy, style::Dimension};
+
+        let mut taffy = Taffy::new();
+        let items = [20.0, 24.0, 27.0, 28.0].map(|max_width| {
+            taffy
+                .new_leaf(FlexboxLayout {
+                    flex_grow: 1.0,
+                    flex_basis: Dimension::Points(0.0),
+                    max_size: Size { width: Dimension::Points(max_width), height: Dimension::Undefined },
+                    ..Default::default()
+                })
+                .unwrap()
+        });
+        let container = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    size: Size { width: Dimension::Points(100.0), height: Dimension::Points(10.0) },
+                    ..Default::default()
+                },
+                &items,
+            )
+            .unwrap();
+
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+
+        // Each pass hands the space freed by the previous one to the remaining items, which pushes the
+        // next one past its max: 25px each freezes the first two, 28px each freezes the third, and the
+        // last one then overshoots to 29px and is clamped too, leaving 1px unused
+        let widths = items.map(|item| taffy.layout(item).unwrap().size.width);
+        assert_eq!(widths, [20.0, 24.0, 27.0, 28.0]);
+    }
+
+    #[test]
+    fn line_min_cross_size_grows_short_lines() {
+        use crate::{node::Taffy, style::Dimension};
+
+        let mut taffy = Taffy::new();
+        let items = [10.0, 40.0, 10.0].map(|height| {
+            taffy
+                .new_leaf(FlexboxLayout {
+                    size: Size { width: Dimension::Points(60.0), height: Dimension::Points(height) },
+                    ..Default::default()
+                })
+                .unwrap()
+        });
+        let container = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    line_min_cross_size: Dimension::Points(30.0),
+                    size: Size { width: Dimension::Points(100.0), height: Dimension::Auto },
+                    ..FlexboxLayout::row().wrap()
+                },
+                &items,
+            )
+            .unwrap();
+
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+
+        // Each item wraps onto its own line; only the line of the tall item exceeds the minimum
+        let ys = items.map(|item| taffy.layout(item).unwrap().location.y);
+        assert_eq!(ys, [0.0, 30.0, 70.0]);
+        assert_eq!(taffy.layout(container).unwrap().size.height, 100.0);
+    }
+
+    #[test]
+    fn undefined_flex_basis_behaves_like_auto() {
+        use crate::{geometry::Point, node::Taffy, style::Dimension, sys::Vec};
+
+        fn layout_with_flex_basis(flex_basis: Dimension) -> Vec<(Point<f32>, Size<f32>)> {
+            let mut taffy = Taffy::new();
+            let sized = taffy
+                .new_leaf(FlexboxLayout {
+                    flex_basis,
+                    size: Size { width: Dimension::Points(30.0), height: Dimension::Auto },
+                    ..Default::default()
+                })
+                .unwrap();
+            let with_ratio =
+                taffy.new_leaf(FlexboxLayout { flex_basis, aspect_ratio: Some(2.0), ..Default::default() }).unwrap();
+            let content = taffy
+                .new_leaf(FlexboxLayout {
+                    size: Size { width: Dimension::Points(15.0), height: Dimension::Points(10.0) },
+                    ..Default::default()
+                })
+                .unwrap();
+            let with_content =
+                taffy.new_with_children(FlexboxLayout { flex_basis, ..Default::default() }, &[content]).unwrap();
+            let container = taffy
+                .new_with_children(
+                    FlexboxLayout {
+                        size: Size { width: Dimension::Points(200.0), height: Dimension::Points(20.0) },
+                        ..Default::default()
+                    },
+                    &[sized, with_ratio, with_content],
+                )
+                .unwrap();
+
+            taffy.compute_layout(container, Size::undefined()).unwrap();
+
+            [sized, with_ratio, with_content, content]
+                .into_iter()
+                .map(|node| taffy.layout(node).unwrap())
+                .map(|layout| (layout.location, layout.size))
+                .collect()
+        }
+
+        let auto = layout_with_flex_basis(Dimension::Auto);
+        assert_eq!(auto[0].1.width, 30.0);
+        assert_eq!(auto[1].1.width, 40.0);
+        assert_eq!(auto[2].1.width, 15.0);
+        assert_eq!(layout_with_flex_basis(Dimension::Undefined), auto);
+    }
+
+    /// The min and max heights conflict: the min height wins, and the ratio gives a width of 120px unless capped
+    #[rstest::rstest]
+    #[case(Dimension::Undefined, 120.0)]
+    #[case(Dimension::Points(100.0), 100.0)]
+    fn aspect_ratio_with_conflicting_min_and_max_sizes(#[case] max_width: Dimension, #[case] expected_width: f32) {
+        use crate::node::Taffy;
+
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(FlexboxLayout {
+                aspect_ratio: Some(2.0),
+                min_size: Size { width: Dimension::Undefined, height: Dimension::Points(60.0) },
+                max_size: Size { width: max_width, height: Dimension::Points(40.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let container = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    size: Size { width: Dimension::Points(300.0), height: Dimension::Points(100.0) },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+
+        for _ in 0..3 {
+            taffy.mark_dirty(container).unwrap();
+            taffy.compute_layout(container, Size::undefined()).unwrap();
+            assert_eq!(taffy.layout(child).unwrap().size.width, expected_width);
+        }
+    }
+
+    #[rstest::rstest]
+    #[case::zero(0.0)]
+    #[case::negative(-1.0)]
+    #[case::nan(f32::NAN)]
+    #[case::infinite(f32::INFINITY)]
+    fn invalid_aspect_ratio_is_ignored(#[case] aspect_ratio: f32) {
+        use crate::{node::Taffy, style::AlignItems};
+
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(FlexboxLayout {
+                size: Size { width: Dimension::Points(100.0), height: Dimension::Auto },
+                aspect_ratio: Some(aspect_ratio),
+                ..Default::default()
+            })
+            .unwrap();
+        let container = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    align_items: AlignItems::FlexStart,
+                    size: Size { width: Dimension::Points(300.0), height: Dimension::Points(100.0) },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+
+        // Without an aspect ratio, the height of an empty item is zero
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 100.0, height: 0.0 });
+    }
+
+    #[test]
+    fn relative_percentage_offsets_resolve_against_the_container() {
+        use crate::{geometry::Point, node::Taffy};
+
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(FlexboxLayout {
+                position: Rect { start: Dimension::Percent(0.1), top: Dimension::Percent(0.1), ..Default::default() },
+                size: Size { width: Dimension::Points(10.0), height: Dimension::Points(10.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let container = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    size: Size { width: Dimension::Points(200.0), height: Dimension::Auto },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+
+        // The height of the container is indefinite, so the vertical offset is treated as zero
+        assert_eq!(taffy.layout(child).unwrap().location, Point { x: 20.0, y: 0.0 });
+    }
+
+    #[test]
+    fn layout_contains_resolved_box_model() {
+        use crate::node::Taffy;
+
+        let mut taffy = Taffy::new();
+        let child_style = FlexboxLayout {
+            margin: Rect {
+                start: Dimension::Percent(0.05),
+                end: Dimension::Points(4.0),
+                top: Dimension::Auto,
+                bottom: Dimension::Undefined,
+            },
+            border: Rect::from_points(2.0, 2.0, 2.0, 2.0),
+            padding: Rect {
+                start: Dimension::Percent(0.1),
+                end: Dimension::Percent(0.1),
+                top: Dimension::Percent(0.1),
+                bottom: Dimension::Percent(0.1),
+            },
+            ..Default::default()
+        };
+        let child = taffy.new_leaf(child_style).unwrap();
+        let container = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    size: Size { width: Dimension::Points(200.0), height: Dimension::Points(100.0) },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+
+        let layout = taffy.layout(child).unwrap();
+        // Percentages resolve against the width of the container
+        assert_eq!(layout.padding, child_style.padding.resolve_or_default(Some(200.0)));
+        assert_eq!(layout.padding, Rect { start: 20.0, end: 20.0, top: 20.0, bottom: 20.0 });
+        assert_eq!(layout.border, Rect { start: 2.0, end: 2.0, top: 2.0, bottom: 2.0 });
+        // The auto top margin absorbs the free cross space
+        assert_eq!(layout.margin, Rect { start: 10.0, end: 4.0, top: 56.0, bottom: 0.0 });
+        // The reported box model matches the one used to size the node
+        assert_eq!(layout.size.width, layout.padding.horizontal_axis_sum() + layout.border.horizontal_axis_sum());
+
+        assert_eq!(taffy.layout(container).unwrap().padding, Rect::ZERO);
+    }
+
+    #[test]
+    fn rounding_applies_to_the_resolved_box_model() {
+        use crate::node::Taffy;
+
+        let mut taffy = Taffy::new();
+        taffy.enable_rounding();
+        let node = taffy
+            .new_leaf(FlexboxLayout {
+                margin: Rect::from_points(1.6, 1.6, 1.6, 1.6),
+                border: Rect::from_points(0.2, 0.2, 0.2, 0.2),
+                padding: Rect::from_points(2.5, 2.5, 2.5, 2.5),
+                ..Default::default()
+            })
+            .unwrap();
+
+        taffy.compute_layout(node, Size::undefined()).unwrap();
+
+        let layout = taffy.layout(node).unwrap();
+        assert_eq!(layout.margin, Rect { start: 2.0, end: 2.0, top: 2.0, bottom: 2.0 });
+        assert_eq!(layout.border, Rect::ZERO);
+        assert_eq!(layout.padding, Rect { start: 3.0, end: 3.0, top: 3.0, bottom: 3.0 });
+        // Each value is rounded on its own, so the insets can exceed the rounded size
+        assert_eq!(layout.size, Size { width: 5.0, height: 5.0 });
+    }
+
+    #[test]
+    fn row_reverse_mirrors_main_axis_but_not_cross_axis_alignment() {
+        use crate::{node::Taffy, style::AlignItems, sys::Vec};
+
+        fn item_locations(flex_direction: FlexDirection) -> Vec<(f32, f32)> {
+            let mut taffy = Taffy::new();
+            let first =
+                taffy.new_leaf(FlexboxLayout { size: Size::from_points(20.0, 10.0), ..Default::default() }).unwrap();
+            let second =
+                taffy.new_leaf(FlexboxLayout { size: Size::from_points(30.0, 20.0), ..Default::default() }).unwrap();
+            let container = taffy
+                .new_with_children(
+                    FlexboxLayout {
+                        flex_direction,
+                        align_items: AlignItems::FlexEnd,
+                        size: Size::from_points(100.0, 50.0),
+                        ..Default::default()
+                    },
+                    &[first, second],
+                )
+                .unwrap();
+
+            taffy.compute_layout(container, Size::undefined()).unwrap();
+
+            [first, second]
+                .into_iter()
+                .map(|item| taffy.layout(item).unwrap().location)
+                .map(|location| (location.x, location.y))
+                .collect()
+        }
+
+        assert_eq!(item_locations(FlexDirection::Row).as_slice(), [(0.0, 40.0), (20.0, 30.0)]);
+        assert_eq!(item_locations(FlexDirection::RowReverse).as_slice(), [(80.0, 40.0), (50.0, 30.0)]);
+    }
+
+    #[test]
+    fn min_size_wins_over_a_smaller_max_size() {
+        use crate::node::Taffy;
+
+        let mut taffy = Taffy::new();
+        let conflicting = FlexboxLayout {
+            min_size: Size { width: Dimension::Points(100.0), height: Dimension::Points(30.0) },
+            max_size: Size { width: Dimension::Points(50.0), height: Dimension::Points(10.0) },
+            ..Default::default()
+        };
+        let child = taffy.new_leaf(conflicting).unwrap();
+        let container = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    size: Size { width: Dimension::Points(200.0), height: Dimension::Points(200.0) },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        let root = taffy.new_leaf(conflicting).unwrap();
+
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+        taffy.compute_layout(root, Size::undefined()).unwrap();
+
+        for node in [child, root] {
+            assert_eq!(taffy.layout(node).unwrap().size, Size { width: 100.0, height: 30.0 });
+        }
+    }
+
+    #[rstest::rstest]
+    #[case(FlexDirection::Row)]
+    #[case(FlexDirection::Column)]
+    fn aspect_ratio_applies_to_a_resolved_percentage_size(#[case] flex_direction: FlexDirection) {
+        use crate::node::Taffy;
+
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(FlexboxLayout {
+                size: Size { width: Dimension::Percent(0.5), height: Dimension::Auto },
+                aspect_ratio: Some(2.0),
+                ..Default::default()
+            })
+            .unwrap();
+        let container = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    flex_direction,
+                    size: Size { width: Dimension::Points(400.0), height: Dimension::Auto },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 200.0, height: 100.0 });
+    }
+
+    /// A 300px wide column container stretches a 2:1 item to its width, which makes the item 150px tall
+    /// unless padding or margins leave it less room
+    #[rstest::rstest]
+    #[case::plain(0.0, 0.0, 300.0)]
+    #[case::padded(20.0, 0.0, 260.0)]
+    #[case::with_margins(0.0, 20.0, 260.0)]
+    fn aspect_ratio_derives_the_main_size_from_a_stretched_cross_size(
+        #[case] padding: f32,
+        #[case] margin: f32,
+        #[case] expected_width: f32,
+    ) {
+        use crate::node::Taffy;
+
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(FlexboxLayout {
+                margin: Rect::from_points(margin, margin, 0.0, 0.0),
+                aspect_ratio: Some(2.0),
+                ..Default::default()
+            })
+            .unwrap();
+        let sibling =
+            taffy.new_leaf(FlexboxLayout { size: Size::from_points(240.0, 10.0), ..Default::default() }).unwrap();
+        let container = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    padding: Rect::from_points(padding, padding, 0.0, 0.0),
+                    size: Size { width: Dimension::Points(300.0), height: Dimension::Auto },
+                    ..FlexboxLayout::column()
+                },
+                &[child, sibling],
+            )
+            .unwrap();
+
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+
+        let size = taffy.layout(child).unwrap().size;
+        assert_eq!(size, Size { width: expected_width, height: expected_width / 2.0 });
+        assert_eq!(taffy.layout(sibling).unwrap().location.y, expected_width / 2.0);
+    }
+
+    #[test]
+    fn max_content_flex_basis_uses_the_measured_size() {
+        use crate::node::{MeasureFunc, Taffy};
+
+        let mut taffy = Taffy::new();
+        let measured = taffy
+            .new_leaf_with_measure(
+                FlexboxLayout { flex_grow: 1.0, flex_basis: Dimension::MaxContent, ..Default::default() },
+                MeasureFunc::Raw(|known| Size { width: known.width.unwrap_or(80.0), height: 10.0 }),
+            )
+            .unwrap();
+        let sibling = taffy
+            .new_leaf(FlexboxLayout { flex_grow: 1.0, flex_basis: Dimension::Points(0.0), ..Default::default() })
+            .unwrap();
+        let container = taffy
+            .new_with_children(
+                FlexboxLayout { size: Size::from_points(200.0, 10.0), ..Default::default() },
+                &[measured, sibling],
+            )
+            .unwrap();
+
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+
+        // The measured item starts from its 80px max-content width, and the remaining 120px is shared equally
+        assert_eq!(taffy.layout(measured).unwrap().size.width, 140.0);
+        assert_eq!(taffy.layout(sibling).unwrap().size.width, 60.0);
+    }
+
+    /// A wrapping row of two 30px wide items is 30px wide at its min-content size, and 60px wide at its max-content size
+    #[rstest::rstest]
+    #[case::min_content(Dimension::MinContent, 30.0)]
+    #[case::max_content(Dimension::MaxContent, 60.0)]
+    fn intrinsic_flex_basis_uses_the_content_size(#[case] flex_basis: Dimension, #[case] expected_width: f32) {
+        use crate::node::Taffy;
+
+        let mut taffy = Taffy::new();
+        let leaf_style = FlexboxLayout { size: Size::from_points(30.0, 10.0), ..Default::default() };
+        let leaves = [(); 2].map(|_| taffy.new_leaf(leaf_style).unwrap());
+        let item = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    flex_basis,
+                    // Otherwise the automatic min size would hold the item at its max-content width
+                    min_size: Size { width: Dimension::Points(0.0), height: Dimension::Auto },
+                    ..FlexboxLayout::row().wrap()
+                },
+                &leaves,
+            )
+            .unwrap();
+        let container = taffy
+            .new_with_children(FlexboxLayout { size: Size::from_points(200.0, 100.0), ..Default::default() }, &[item])
+            .unwrap();
+
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+
+        assert_eq!(taffy.layout(item).unwrap().size.width, expected_width);
+    }
+
+    /// A wrapping row of two 30px wide items has a 30px min-content width, which holds it above the 20px container width
+    #[test]
+    fn min_content_min_size_keeps_a_shrinking_item_at_its_content_width() {
+        use crate::node::Taffy;
+
+        let mut taffy = Taffy::new();
+        let leaf_style = FlexboxLayout { size: Size::from_points(30.0, 10.0), ..Default::default() };
+        let leaves = [(); 2].map(|_| taffy.new_leaf(leaf_style).unwrap());
+        let item = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    min_size: Size { width: Dimension::MinContent, height: Dimension::Auto },
+                    ..FlexboxLayout::row().wrap()
+                },
+                &leaves,
+            )
+            .unwrap();
+        let container = taffy
+            .new_with_children(FlexboxLayout { size: Size::from_points(20.0, 100.0), ..Default::default() }, &[item])
+            .unwrap();
+
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+
+        assert_eq!(taffy.layout(item).unwrap().size.width, 30.0);
+    }
+
+    /// A wrapping row of two 30px wide items is 30px wide at its min-content size, and 60px wide at its max-content size
+    #[rstest::rstest]
+    #[case::min_content(Dimension::MinContent, 30.0)]
+    #[case::max_content(Dimension::MaxContent, 60.0)]
+    fn intrinsic_max_size_stops_a_growing_item_at_its_content_width(
+        #[case] max_width: Dimension,
+        #[case] expected_width: f32,
+    ) {
+        use crate::node::Taffy;
+
+        let mut taffy = Taffy::new();
+        let leaf_style = FlexboxLayout { size: Size::from_points(30.0, 10.0), ..Default::default() };
+        let leaves = [(); 2].map(|_| taffy.new_leaf(leaf_style).unwrap());
+        let item = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    flex_grow: 1.0,
+                    // Otherwise the automatic min size would hold the item at its max-content width
+                    min_size: Size { width: Dimension::Points(0.0), height: Dimension::Auto },
+                    max_size: Size { width: max_width, height: Dimension::Auto },
+                    ..FlexboxLayout::row().wrap()
+                },
+                &leaves,
+            )
+            .unwrap();
+        let container = taffy
+            .new_with_children(FlexboxLayout { size: Size::from_points(200.0, 100.0), ..Default::default() }, &[item])
+            .unwrap();
+
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+
+        assert_eq!(taffy.layout(item).unwrap().size.width, expected_width);
+    }
+
+    #[test]
+    fn leaf_measure_is_reused_when_performing_layout() {
+        use crate::node::MeasureFunc;
+        use core::sync::atomic::{AtomicU32, Ordering};
+
+        static NUM_MEASURES: AtomicU32 = AtomicU32::new(0);
+
+        let mut forest = Forest::with_capacity(1);
+        let leaf = forest.new_leaf_with_measure(
+            FlexboxLayout::default(),
+            MeasureFunc::Raw(|_| {
+                NUM_MEASURES.fetch_add(1, Ordering::Relaxed);
+                Size { width: 10.0, height: 10.0 }
+            }),
+        );
+
+        // The intrinsic sizing pass, followed by the final layout pass with the same constraints
+        forest.compute_preliminary(leaf, Size::undefined(), Size::undefined(), false);
+        forest.compute_preliminary(leaf, Size::undefined(), Size::undefined(), true);
+
+        assert_eq!(NUM_MEASURES.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn invalid_measurements_are_sanitized() {
+        use super::sanitize_measurement;
+
+        let valid = Size { width: 10.0, height: 0.0 };
+        assert_eq!(sanitize_measurement(valid), valid);
+
+        let sanitized = sanitize_measurement(Size { width: f32::NAN, height: f32::INFINITY });
+        assert_eq!(sanitized, Size { width: 0.0, height: 0.0 });
+
+        let sanitized = sanitize_measurement(Size { width: -10.0, height: 10.0 });
+        assert_eq!(sanitized, Size { width: 0.0, height: 10.0 });
+    }
+
+    #[test]
+    fn final_layout_is_not_evicted_by_a_measurement() {
+        let mut forest = Forest::with_capacity(2);
+        let leaf = forest.new_leaf(FlexboxLayout::default());
+        let node = forest.new_with_children(FlexboxLayout::default(), [leaf].into_iter().collect());
+
+        let parent_size = Size { width: Some(200.0), height: Some(200.0) };
+        let measured_size = Size { width: Some(50.0), height: Some(50.0) };
+        let laid_out_size = Size { width: Some(100.0), height: Some(100.0) };
+
+        // The intrinsic sizing pass measures the node at one definite size, and the final pass lays it out at another
+        forest.compute_preliminary(node, measured_size, parent_size, false);
+        forest.compute_preliminary(node, laid_out_size, parent_size, true);
+        forest.compute_preliminary(node, measured_size, parent_size, false);
+
+        assert!(forest.compute_from_cache(node, laid_out_size, parent_size, true).is_some());
+    }
+
+    #[rstest::rstest]
+    #[case::column(FlexDirection::Column, 0.0, 50.0)]
+    #[case::column_reverse(FlexDirection::ColumnReverse, 50.0, 0.0)]
+    fn collapse_margins_keeps_the_larger_of_adjacent_margins(
+        #[case] flex_direction: FlexDirection,
+        #[case] first_y: f32,
+        #[case] second_y: f32,
+    ) {
+        use crate::node::Taffy;
+
+        let mut taffy = Taffy::new();
+        let item_style = |margin| FlexboxLayout {
+            size: Size { width: Dimension::Points(10.0), height: Dimension::Points(30.0) },
+            margin,
+            ..Default::default()
+        };
+        let (first_margin, second_margin) = if flex_direction.is_reverse() {
+            (
+                Rect { top: Dimension::Points(10.0), ..Default::default() },
+                Rect { bottom: Dimension::Points(20.0), ..Default::default() },
+            )
+        } else {
+            (
+                Rect { bottom: Dimension::Points(10.0), ..Default::default() },
+                Rect { top: Dimension::Points(20.0), ..Default::default() },
+            )
+        };
+        let first = taffy.new_leaf(item_style(first_margin)).unwrap();
+        let second = taffy.new_leaf(item_style(second_margin)).unwrap();
+        let container = taffy
+            .new_with_children(
+                FlexboxLayout { flex_direction, collapse_margins: true, ..Default::default() },
+                &[first, second],
+            )
+            .unwrap();
+
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+
+        assert_eq!(taffy.layout(first).unwrap().location.y, first_y);
+        assert_eq!(taffy.layout(second).unwrap().location.y, second_y);
+        assert_eq!(taffy.layout(container).unwrap().size.height, 80.0);
+
+        taffy.update_style(container, |style| style.collapse_margins = false).unwrap();
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+
+        assert_eq!(taffy.layout(container).unwrap().size.height, 90.0);
+    }
+
+    #[test]
+    fn order_does_not_change_the_paint_order() {
+        use crate::node::Taffy;
+
+        let mut taffy = Taffy::new();
+        let items = [1, -1, 0].map(|order| taffy.new_leaf(FlexboxLayout { order, ..Default::default() }).unwrap());
+        let container = taffy.new_with_children(FlexboxLayout::default(), &items).unwrap();
+
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+
+        for (index, item) in items.iter().enumerate() {
+            assert_eq!(taffy.layout(*item).unwrap().order, index as u32);
+        }
+    }
+
+    #[rstest::rstest]
+    #[case::auto_uses_the_content_size(Dimension::Auto, 100.0)]
+    #[case::undefined_uses_the_content_size(Dimension::Undefined, 100.0)]
+    #[case::zero_allows_shrinking_below_the_content_size(Dimension::Points(0.0), 50.0)]
+    #[case::explicit_replaces_the_content_size(Dimension::Points(70.0), 70.0)]
+    fn auto_min_size_of_flex_items_is_content_based(#[case] min_width: Dimension, #[case] expected_width: f32) {
+        use crate::node::Taffy;
+
+        let mut taffy = Taffy::new();
+        let content = taffy
+            .new_leaf(FlexboxLayout {
+                size: Size { width: Dimension::Points(100.0), height: Dimension::Points(10.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let item = taffy
+            .new_with_children(
+                FlexboxLayout { min_size: Size { width: min_width, height: Dimension::Auto }, ..Default::default() },
+                &[content],
+            )
+            .unwrap();
+        let container = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    size: Size { width: Dimension::Points(50.0), height: Dimension::Points(10.0) },
+                    ..Default::default()
+                },
+                &[item],
+            )
+            .unwrap();
+
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+
+        assert_eq!(taffy.layout(item).unwrap().size.width, expected_width);
+    }
+
+    #[test]
+    fn auto_min_and_max_sizes_of_other_nodes_are_unconstrained() {
+        use crate::{node::Taffy, style::PositionType};
+
+        let mut taffy = Taffy::new();
+        let absolute = taffy
+            .new_leaf(FlexboxLayout {
+                position_type: PositionType::Absolute,
+                min_size: Size::AUTO,
+                max_size: Size::AUTO,
+                ..Default::default()
+            })
+            .unwrap();
+        let growing =
+            taffy.new_leaf(FlexboxLayout { flex_grow: 1.0, max_size: Size::AUTO, ..Default::default() }).unwrap();
+        let container = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    size: Size { width: Dimension::Points(500.0), height: Dimension::Points(10.0) },
+                    ..Default::default()
+                },
+                &[absolute, growing],
+            )
+            .unwrap();
+
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+
+        // An `auto` min size is no lower bound outside of flex layout, and an `auto` max size is never an upper bound
+        assert_eq!(taffy.layout(absolute).unwrap().size, Size::ZERO);
+        assert_eq!(taffy.layout(growing).unwrap().size.width, 500.0);
+    }
 }
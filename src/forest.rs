@@ -1,11 +1,15 @@
 //! Forest - a struct-of-arrays data structure for storing node trees.
 //!
 //! Backing data structure for `Taffy` structs.
+use crate::geometry::Size;
 use crate::layout::{Cache, Layout};
 use crate::node::{MeasureFunc, NodeId};
 use crate::style::FlexboxLayout;
 use crate::sys::{new_vec_with_capacity, ChildrenVec, ParentsVec, Vec};
 
+/// The number of cache slots held by each node: one per constraint mode of the node's size
+pub(crate) const CACHE_SIZE: usize = 4;
+
 /// Layout information for a given [`Node`](crate::node::Node)
 ///
 /// Stored in a [`Forest`].
@@ -16,12 +20,38 @@ pub(crate) struct NodeData {
     pub(crate) measure: Option<MeasureFunc>,
     /// The results of the layout computation
     pub(crate) layout: Layout,
-    /// The primary cached results of the layout computation
-    pub(crate) main_size_layout_cache: Option<Cache>,
-    /// Secondary cached results of the layout computation
-    pub(crate) other_layout_cache: Option<Cache>,
+    /// The cached results of the layout computation
+    ///
+    /// Each slot holds the result for one combination of definite and indefinite width and height.
+    pub(crate) layout_cache: [Option<Cache>; CACHE_SIZE],
+    /// The cached result of the last computation that performed layout rather than only measuring the node
+    ///
+    /// This is kept apart from [`NodeData::layout_cache`], so that measuring the node at the size it was laid out at
+    /// doesn't evict the layout, which would then have to be performed again by the final pass of its parent.
+    pub(crate) final_layout_cache: Option<Cache>,
     /// Does this node's layout need to be recomputed?
     pub(crate) is_dirty: bool,
+    /// The index of the flex line that this node was placed in by its parent during the last layout
+    ///
+    /// This is `None` for nodes that were not laid out as flex items, such as absolutely positioned or hidden nodes.
+    pub(crate) flex_line: Option<usize>,
+    /// Should the stored layout of this node and its descendants be reused instead of being recomputed?
+    pub(crate) is_frozen: bool,
+    /// Is this node positioned relative to a portal anchor rather than its parent?
+    ///
+    /// Like absolutely positioned nodes, such nodes take no space in their parent.
+    pub(crate) is_portal: bool,
+    /// Which axes of this node's percentage size couldn't be resolved during the last layout
+    ///
+    /// A percentage can't be resolved when the matching axis of the parent's inner size is indefinite.
+    pub(crate) unresolved_percentage_size: Size<bool>,
+    /// The total time spent computing the layout of this node, including its descendants, during the last layout
+    #[cfg(feature = "profiling")]
+    pub(crate) layout_time: std::time::Duration,
+    /// The cached size of this node when laid out with no available space
+    pub(crate) min_content_size: Option<Size<f32>>,
+    /// The cached size of this node when laid out with unlimited available space
+    pub(crate) max_content_size: Option<Size<f32>>,
 }
 
 impl NodeData {
@@ -31,10 +61,18 @@ impl NodeData {
         Self {
             style,
             measure: Some(measure),
-            main_size_layout_cache: None,
-            other_layout_cache: None,
+            layout_cache: [None; CACHE_SIZE],
+            final_layout_cache: None,
             layout: Layout::new(),
             is_dirty: true,
+            flex_line: None,
+            is_frozen: false,
+            is_portal: false,
+            unresolved_percentage_size: Size { width: false, height: false },
+            #[cfg(feature = "profiling")]
+            layout_time: std::time::Duration::ZERO,
+            min_content_size: None,
+            max_content_size: None,
         }
     }
 
@@ -44,10 +82,18 @@ impl NodeData {
         Self {
             style,
             measure: None,
-            main_size_layout_cache: None,
-            other_layout_cache: None,
+            layout_cache: [None; CACHE_SIZE],
+            final_layout_cache: None,
             layout: Layout::new(),
             is_dirty: true,
+            flex_line: None,
+            is_frozen: false,
+            is_portal: false,
+            unresolved_percentage_size: Size { width: false, height: false },
+            #[cfg(feature = "profiling")]
+            layout_time: std::time::Duration::ZERO,
+            min_content_size: None,
+            max_content_size: None,
         }
     }
 
@@ -56,8 +102,10 @@ impl NodeData {
     /// This clears any cached data and signals that the data must be recomputed.
     #[inline]
     fn mark_dirty(&mut self) {
-        self.main_size_layout_cache = None;
-        self.other_layout_cache = None;
+        self.layout_cache = [None; CACHE_SIZE];
+        self.final_layout_cache = None;
+        self.min_content_size = None;
+        self.max_content_size = None;
         self.is_dirty = true;
     }
 }
@@ -74,6 +122,14 @@ pub(crate) struct Forest {
     ///
     /// The indexes in the outer vector correspond to the position of the child [`NodeData`]
     pub(crate) parents: Vec<ParentsVec<NodeId>>,
+    /// The first size returned by a [`MeasureFunc`] that was not finite or was negative, and the node it measured
+    ///
+    /// This is only recorded in debug builds, and is reported by [`Taffy`](crate::node::Taffy) once a computation ends.
+    #[cfg(debug_assertions)]
+    pub(crate) invalid_measurement: Option<(NodeId, Size<f32>)>,
+    /// The number of times a tree has been laid out with [`Forest::compute`], so that tests can tell when it is skipped
+    #[cfg(test)]
+    pub(crate) compute_count: usize,
 }
 
 impl Forest {
@@ -84,6 +140,10 @@ impl Forest {
             nodes: new_vec_with_capacity(capacity),
             children: new_vec_with_capacity(capacity),
             parents: new_vec_with_capacity(capacity),
+            #[cfg(debug_assertions)]
+            invalid_measurement: None,
+            #[cfg(test)]
+            compute_count: 0,
         }
     }
 
@@ -126,6 +186,13 @@ impl Forest {
         self.mark_dirty(parent)
     }
 
+    /// Adds `child` as the n-th child of `parent`, shifting any later children along
+    pub(crate) fn add_child_at_index(&mut self, parent: NodeId, child_index: usize, child: NodeId) {
+        self.parents[child].push(parent);
+        self.children[parent].insert(child_index, child);
+        self.mark_dirty(parent)
+    }
+
     /// Removes all nodes and resets the data structure
     ///
     /// The capacity is retained.
@@ -133,6 +200,10 @@ impl Forest {
         self.nodes.clear();
         self.children.clear();
         self.parents.clear();
+        #[cfg(debug_assertions)]
+        {
+            self.invalid_measurement = None;
+        }
     }
 
     /// Removes the specified `node`
@@ -206,6 +277,47 @@ impl Forest {
         }
     }
 
+    /// Renumbers every node so that each tree is stored in depth-first (pre-order) order
+    ///
+    /// Roots are visited in order of their current [`NodeId`], so a node's descendants are stored directly after it.
+    /// This restores traversal locality after many insertions and removals have scattered the ids.
+    /// Returns the `(old, new)` [`NodeId`] of every node, ordered by the new id.
+    pub(crate) fn compact(&mut self) -> Vec<(NodeId, NodeId)> {
+        let len = self.len();
+
+        // The old id of the node that will be stored at each new position
+        let mut order: Vec<NodeId> = new_vec_with_capacity(len);
+        let mut visited: Vec<bool> = (0..len).map(|_| false).collect();
+        let mut stack: Vec<NodeId> = new_vec_with_capacity(len);
+        for root in (0..len).filter(|&node| self.parents[node].is_empty()) {
+            stack.push(root);
+            while let Some(node) = stack.pop() {
+                if visited[node] {
+                    continue;
+                }
+                visited[node] = true;
+                order.push(node);
+                stack.extend(self.children[node].iter().rev().copied());
+            }
+        }
+        // Nodes that are only reachable through a cycle keep their relative order at the end
+        order.extend((0..len).filter(|&node| !visited[node]));
+
+        let mut new_ids: Vec<NodeId> = (0..len).collect();
+        for (new, &old) in order.iter().enumerate() {
+            new_ids[old] = new;
+        }
+
+        let mut old_nodes: Vec<Option<NodeData>> = core::mem::take(&mut self.nodes).into_iter().map(Some).collect();
+        self.nodes = order.iter().map(|&old| old_nodes[old].take().unwrap()).collect();
+        self.children =
+            order.iter().map(|&old| self.children[old].iter().map(|&child| new_ids[child]).collect()).collect();
+        self.parents =
+            order.iter().map(|&old| self.parents[old].iter().map(|&parent| new_ids[parent]).collect()).collect();
+
+        order.iter().map(|&old| (old, new_ids[old])).collect()
+    }
+
     /// Breaks the link between the `parent` node and the `child` node
     ///
     /// The `child`'s data is not removed.
@@ -242,6 +354,31 @@ impl Forest {
         mark_dirty_recursive(&mut self.nodes, &self.parents, node);
     }
 
+    /// Marks the `node` as needing layout recalculation, while keeping its own cached results
+    ///
+    /// This is used when a change to the node can only affect how its ancestors lay it out.
+    /// The cached layout information of all of its ancestors is cleared.
+    pub(crate) fn mark_dirty_preserving_cache(&mut self, node: NodeId) {
+        self.nodes[node].is_dirty = true;
+        // The intrinsic sizes depend on the node's own size, so they can't be preserved
+        self.nodes[node].min_content_size = None;
+        self.nodes[node].max_content_size = None;
+
+        for index in 0..self.parents[node].len() {
+            let parent = self.parents[node][index];
+            self.mark_dirty(parent);
+        }
+    }
+
+    /// Marks every node in the forest as needing layout recalculation
+    ///
+    /// Any cached layout information is cleared.
+    pub(crate) fn mark_all_dirty(&mut self) {
+        for node in &mut self.nodes {
+            node.mark_dirty();
+        }
+    }
+
     /// Returns the number of elements in the forest.
     #[inline]
     pub(crate) fn len(&self) -> usize {
@@ -321,7 +458,7 @@ mod tests {
         let node = &forest.nodes[id];
         assert_eq!(id, 0);
         assert_eq!(node.style, s1);
-        assert!(node_measure_eq(&node, measure_fn1));
+        assert!(node_measure_eq(node, measure_fn1));
         assert_forest_size(&forest, 1);
     }
 
@@ -339,7 +476,7 @@ mod tests {
         let node = &forest.nodes[id];
         assert_eq!(id, 1);
         assert_eq!(node.style, s2);
-        assert!(node_measure_eq(&node, measure_fn2));
+        assert!(node_measure_eq(node, measure_fn2));
         assert_forest_size(&forest, 2);
     }
 
@@ -412,6 +549,20 @@ mod tests {
         assert!(parent.is_dirty);
     }
 
+    #[test]
+    fn add_child_at_index() {
+        let mut forest = Forest::with_capacity(3);
+        let parent_id = add_default_leaf(&mut forest);
+        let c1_id = add_default_leaf(&mut forest);
+        let c2_id = add_default_leaf(&mut forest);
+        forest.add_child(parent_id, c2_id);
+        forest.add_child_at_index(parent_id, 0, c1_id);
+
+        assert_eq!(forest.parents[c1_id][0], parent_id);
+        assert_eq!(forest.children[parent_id].as_slice(), &[c1_id, c2_id]);
+        assert!(forest.nodes[parent_id].is_dirty);
+    }
+
     #[test]
     fn clear() {
         let mut forest = Forest::with_capacity(1);
@@ -441,8 +592,8 @@ mod tests {
         forest.add_child(parent_id, c2_id);
 
         let moved_id = forest.swap_remove(parent_id);
-        let new_c1_id = parent_id.clone();
-        let new_c2_id = c1_id.clone();
+        let new_c1_id = parent_id;
+        let new_c2_id = c1_id;
 
         assert_eq!(moved_id, Some(c2_id));
         assert_eq!(forest.parents[new_c1_id].len(), 0);
@@ -460,8 +611,8 @@ mod tests {
         forest.add_child(c1_id, c2_id);
 
         let moved_id = forest.swap_remove(parent_id);
-        let new_c1_id = c1_id.clone();
-        let new_c2_id = parent_id.clone();
+        let new_c1_id = c1_id;
+        let new_c2_id = parent_id;
 
         assert_eq!(moved_id, Some(c2_id));
         assert_eq!(forest.parents[new_c1_id].len(), 0);
@@ -480,7 +631,7 @@ mod tests {
         forest.add_child(parent_id, c2_id);
 
         let moved_id = forest.swap_remove(c1_id);
-        let new_c2_id = c1_id.clone();
+        let new_c2_id = c1_id;
 
         assert_eq!(moved_id, Some(c2_id));
         assert_eq!(forest.children[parent_id].len(), 1);
@@ -581,6 +732,40 @@ mod tests {
         assert!(forest.nodes[parent_id].is_dirty);
     }
 
+    #[test]
+    fn compact_renumbers_nodes_in_depth_first_order() {
+        let mut forest = Forest::with_capacity(5);
+        let leaf = add_default_leaf(&mut forest);
+        let inner = add_default_leaf(&mut forest);
+        let sibling = forest.new_leaf(get_non_default_layout(1.0));
+        let root = add_default_leaf(&mut forest);
+        let other_root = add_default_leaf(&mut forest);
+        forest.add_child(inner, leaf);
+        forest.add_child(root, inner);
+        forest.add_child(root, sibling);
+
+        let remapping = forest.compact();
+        let new_id = |old: NodeId| remapping.iter().find(|(from, _)| *from == old).unwrap().1;
+
+        // Depth-first order: root, inner, leaf, sibling, then the next root
+        assert_eq!(remapping.len(), 5);
+        assert_eq!(new_id(root), 0);
+        assert_eq!(new_id(inner), 1);
+        assert_eq!(new_id(leaf), 2);
+        assert_eq!(new_id(sibling), 3);
+        assert_eq!(new_id(other_root), 4);
+        assert!(remapping.iter().enumerate().all(|(index, (_, new))| index == *new));
+
+        assert_forest_size(&forest, 5);
+        assert_eq!(forest.children[0].as_slice(), &[1, 3]);
+        assert_eq!(forest.children[1].as_slice(), &[2]);
+        assert_eq!(forest.parents[1].as_slice(), &[0]);
+        assert_eq!(forest.parents[2].as_slice(), &[1]);
+        assert_eq!(forest.parents[3].as_slice(), &[0]);
+        assert!(forest.parents[4].is_empty());
+        assert_eq!(forest.nodes[3].style, get_non_default_layout(1.0));
+    }
+
     #[test]
     fn len_zero() {
         let forest = Forest::with_capacity(1);
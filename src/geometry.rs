@@ -1,10 +1,15 @@
 //! Geometric primitives useful for layout
 
+use crate::resolve::MaybeResolve;
 use crate::style::{Dimension, FlexDirection};
+use crate::sys::{ceil, floor, round};
+use core::cmp::Ordering;
 use core::ops::Add;
 
 /// An axis-aligned UI rectangle
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///
+/// The default value has every side set to the default value of `T`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(default))]
 pub struct Rect<T> {
@@ -46,6 +51,21 @@ impl<T> Rect<T> {
             bottom: f(self.bottom, size.height),
         }
     }
+
+    /// Transposes the rect, so that `start` and `end` become `top` and `bottom` and vice versa
+    ///
+    /// This is useful for reusing logic written for one orientation in the other.
+    #[must_use]
+    pub fn swap_axes(self) -> Rect<T> {
+        Rect { start: self.top, end: self.bottom, top: self.start, bottom: self.end }
+    }
+}
+
+impl<T> From<(T, T, T, T)> for Rect<T> {
+    /// Creates a [`Rect`] from a `(start, end, top, bottom)` tuple
+    fn from((start, end, top, bottom): (T, T, T, T)) -> Self {
+        Rect { start, end, top, bottom }
+    }
 }
 
 impl<T> Rect<T>
@@ -136,6 +156,14 @@ where
             self.end
         }
     }
+
+    /// The four sides of the [`Rect`], from the perspective of the layout axes
+    ///
+    /// Returns `(main_start, main_end, cross_start, cross_end)`.
+    /// As with the individual accessors, reversed directions use the same sides as their non-reversed counterparts.
+    pub fn logical(&self, direction: FlexDirection) -> (T, T, T, T) {
+        (self.main_start(direction), self.main_end(direction), self.cross_start(direction), self.cross_end(direction))
+    }
 }
 
 impl Rect<f32> {
@@ -147,10 +175,111 @@ impl Rect<f32> {
     pub fn new(start: f32, end: f32, top: f32, bottom: f32) -> Self {
         Self { start, end, top, bottom }
     }
+
+    /// Converts each side into its bit pattern, producing a value that implements [`Eq`] and [`Hash`](core::hash::Hash)
+    ///
+    /// See [`Size::to_bits`] for how `NaN` and negative zero are treated.
+    #[must_use]
+    pub fn to_bits(self) -> Rect<u32> {
+        Rect {
+            start: f32_to_bits(self.start),
+            end: f32_to_bits(self.end),
+            top: f32_to_bits(self.top),
+            bottom: f32_to_bits(self.bottom),
+        }
+    }
+
+    /// Are all four sides finite (neither infinite nor `NaN`)?
+    #[must_use]
+    pub fn is_finite(self) -> bool {
+        self.start.is_finite() && self.end.is_finite() && self.top.is_finite() && self.bottom.is_finite()
+    }
+
+    /// Does this rectangle contain the point `p`?
+    ///
+    /// For this and the other hit-testing methods, the [`Rect`] is interpreted as absolute coordinates
+    /// rather than as the thickness of each side: `start` and `end` are the x-coordinates of the left and right edges,
+    /// and `top` and `bottom` are the y-coordinates of the top and bottom edges, with y increasing downwards.
+    ///
+    /// The left and top edges are inside the rectangle, while the right and bottom edges are not.
+    /// This means that a point on the shared edge of two adjacent rectangles is only contained by one of them.
+    #[must_use]
+    pub fn contains_point(&self, p: Point<f32>) -> bool {
+        p.x >= self.start && p.x < self.end && p.y >= self.top && p.y < self.bottom
+    }
+
+    /// Do this rectangle and `other` overlap?
+    ///
+    /// Both rectangles are interpreted as absolute coordinates, as described in [`Rect::contains_point`].
+    /// Rectangles that only touch along an edge or at a corner do not intersect.
+    #[must_use]
+    pub fn intersects(&self, other: &Rect<f32>) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// The area where this rectangle and `other` overlap, or [`None`] if they don't
+    ///
+    /// Both rectangles are interpreted as absolute coordinates, as described in [`Rect::contains_point`].
+    /// Rectangles that only touch along an edge or at a corner have no intersection.
+    #[must_use]
+    pub fn intersection(&self, other: &Rect<f32>) -> Option<Rect<f32>> {
+        let intersection = Rect {
+            start: self.start.max(other.start),
+            end: self.end.min(other.end),
+            top: self.top.max(other.top),
+            bottom: self.bottom.min(other.bottom),
+        };
+
+        if intersection.start < intersection.end && intersection.top < intersection.bottom {
+            Some(intersection)
+        } else {
+            None
+        }
+    }
+
+    /// Replaces any side that is infinite or `NaN` with `0.0`
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Rect {
+            start: sanitize_f32(self.start),
+            end: sanitize_f32(self.end),
+            top: sanitize_f32(self.top),
+            bottom: sanitize_f32(self.bottom),
+        }
+    }
+
+    /// Linearly interpolates each side between `self` (at `t = 0.0`) and `other` (at `t = 1.0`)
+    #[must_use]
+    pub fn lerp(self, other: Rect<f32>, t: f32) -> Rect<f32> {
+        Rect {
+            start: lerp_f32(self.start, other.start, t),
+            end: lerp_f32(self.end, other.end, t),
+            top: lerp_f32(self.top, other.top, t),
+            bottom: lerp_f32(self.bottom, other.bottom, t),
+        }
+    }
+
+    /// Rounds each side to the nearest whole number, with halfway values rounded away from zero
+    #[must_use]
+    pub fn round(self) -> Rect<f32> {
+        Rect { start: round(self.start), end: round(self.end), top: round(self.top), bottom: round(self.bottom) }
+    }
+
+    /// Rounds each side down to the nearest whole number
+    #[must_use]
+    pub fn floor(self) -> Rect<f32> {
+        Rect { start: floor(self.start), end: floor(self.end), top: floor(self.top), bottom: floor(self.bottom) }
+    }
+
+    /// Rounds each side up to the nearest whole number
+    #[must_use]
+    pub fn ceil(self) -> Rect<f32> {
+        Rect { start: ceil(self.start), end: ceil(self.end), top: ceil(self.top), bottom: ceil(self.bottom) }
+    }
 }
 
 /// The width and height of a [`Rect`]
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(default))]
 pub struct Size<T> {
@@ -179,6 +308,14 @@ impl<T> Size<T> {
         Size { width: f(self.width), height: f(self.height) }
     }
 
+    /// Swaps the width and height
+    ///
+    /// This is useful for reusing logic written for one orientation in the other.
+    #[must_use]
+    pub fn transpose(self) -> Size<T> {
+        Size { width: self.height, height: self.width }
+    }
+
     /// Sets the extent of the main layout axis
     ///
     /// Whether this is the width or height depends on the `direction` provided
@@ -224,9 +361,95 @@ impl<T> Size<T> {
     }
 }
 
+impl<T> From<(T, T)> for Size<T> {
+    /// Creates a [`Size`] from a `(width, height)` tuple
+    fn from((width, height): (T, T)) -> Self {
+        Size { width, height }
+    }
+}
+
 impl Size<f32> {
     /// A [`Size`] with zero width and height
     pub const ZERO: Size<f32> = Self { width: 0.0, height: 0.0 };
+
+    /// Converts the width and height into their bit patterns, producing a value that implements [`Eq`] and [`Hash`](core::hash::Hash)
+    ///
+    /// This makes it possible to use sizes as the key of a map, e.g. when memoizing measurements.
+    /// All `NaN` values map to the same bit pattern, as do `0.0` and `-0.0`.
+    #[must_use]
+    pub fn to_bits(self) -> Size<u32> {
+        Size { width: f32_to_bits(self.width), height: f32_to_bits(self.height) }
+    }
+
+    /// Are both the width and height finite (neither infinite nor `NaN`)?
+    #[must_use]
+    pub fn is_finite(self) -> bool {
+        self.width.is_finite() && self.height.is_finite()
+    }
+
+    /// Replaces a width or height that is infinite or `NaN` with `0.0`
+    ///
+    /// This is applied to the results of [`MeasureFunc`](crate::node::MeasureFunc)s,
+    /// so that a misbehaving measurement cannot poison the rest of the layout.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Size { width: sanitize_f32(self.width), height: sanitize_f32(self.height) }
+    }
+
+    /// Linearly interpolates the width and height between `self` (at `t = 0.0`) and `other` (at `t = 1.0`)
+    ///
+    /// This is useful for animating between two computed layouts.
+    #[must_use]
+    pub fn lerp(self, other: Size<f32>, t: f32) -> Size<f32> {
+        Size { width: lerp_f32(self.width, other.width, t), height: lerp_f32(self.height, other.height, t) }
+    }
+
+    /// Rounds the width and height to the nearest whole number, with halfway values rounded away from zero
+    ///
+    /// Computed layouts are only rounded when [`Taffy::enable_rounding`](crate::node::Taffy::enable_rounding)
+    /// is on; this allows snapping individual results instead.
+    #[must_use]
+    pub fn round(self) -> Size<f32> {
+        self.map(round)
+    }
+
+    /// Rounds the width and height down to the nearest whole number
+    #[must_use]
+    pub fn floor(self) -> Size<f32> {
+        self.map(floor)
+    }
+
+    /// Rounds the width and height up to the nearest whole number
+    #[must_use]
+    pub fn ceil(self) -> Size<f32> {
+        self.map(ceil)
+    }
+
+    /// Scales this size, preserving its aspect ratio, to the largest size that fits within `bounds`
+    ///
+    /// This matches `object-fit: contain` in CSS: one side matches `bounds` and the other is no larger.
+    /// A size without a positive width and height has no aspect ratio to preserve, so [`Size::ZERO`] is returned.
+    #[must_use]
+    pub fn scale_to_fit(self, bounds: Size<f32>) -> Size<f32> {
+        if self.width <= 0.0 || self.height <= 0.0 {
+            return Size::ZERO;
+        }
+        let factor = (bounds.width / self.width).min(bounds.height / self.height);
+        Size { width: self.width * factor, height: self.height * factor }
+    }
+
+    /// Scales this size, preserving its aspect ratio, to the smallest size that covers `bounds`
+    ///
+    /// This matches `object-fit: cover` in CSS: one side matches `bounds` and the other is no smaller.
+    /// A size without a positive width and height has no aspect ratio to preserve, so [`Size::ZERO`] is returned.
+    #[must_use]
+    pub fn scale_to_cover(self, bounds: Size<f32>) -> Size<f32> {
+        if self.width <= 0.0 || self.height <= 0.0 {
+            return Size::ZERO;
+        }
+        let factor = (bounds.width / self.width).max(bounds.height / self.height);
+        Size { width: self.width * factor, height: self.height * factor }
+    }
 }
 
 impl Size<Option<f32>> {
@@ -238,6 +461,26 @@ impl Size<Option<f32>> {
     pub fn new(width: f32, height: f32) -> Self {
         Size { width: Some(width), height: Some(height) }
     }
+
+    /// Converts any defined width and height into their bit patterns, producing a value that implements [`Eq`] and [`Hash`](core::hash::Hash)
+    ///
+    /// See [`Size::to_bits`] for how `NaN` and negative zero are treated.
+    #[must_use]
+    pub fn to_bits(self) -> Size<Option<u32>> {
+        Size { width: self.width.map(f32_to_bits), height: self.height.map(f32_to_bits) }
+    }
+
+    /// Fills in a missing width or height from the other axis and the aspect `ratio` (width divided by height)
+    ///
+    /// The size is returned unchanged unless exactly one axis is `Some` and `ratio` is `Some`.
+    #[must_use]
+    pub fn maybe_apply_aspect_ratio(self, ratio: Option<f32>) -> Size<Option<f32>> {
+        match (self.width, self.height, ratio) {
+            (Some(width), None, Some(ratio)) => Size { width: Some(width), height: Some(width / ratio) },
+            (None, Some(height), Some(ratio)) => Size { width: Some(height * ratio), height: Some(height) },
+            _ => self,
+        }
+    }
 }
 
 impl Size<Dimension> {
@@ -253,6 +496,53 @@ impl Size<Dimension> {
         Size { width: Dimension::Percent(width), height: Dimension::Percent(height) }
     }
 
+    /// Are both the width and height zero, as defined by [`Dimension::is_zero`]?
+    #[must_use]
+    pub fn is_zero(self) -> bool {
+        self.width.is_zero() && self.height.is_zero()
+    }
+
+    /// Resolves both sizes against `parent`, and keeps the larger value on each axis as [`Dimension::Points`]
+    ///
+    /// This combines two lower bounds, such as an author min size and an automatic minimum.
+    /// A dimension that can't be resolved sets no bound and is ignored; if neither can be resolved,
+    /// the dimension of `self` is kept.
+    #[must_use]
+    pub fn max_resolved(self, other: Size<Dimension>, parent: Size<Option<f32>>) -> Size<Dimension> {
+        self.combine_resolved(other, parent, f32::max)
+    }
+
+    /// Resolves both sizes against `parent`, and keeps the smaller value on each axis as [`Dimension::Points`]
+    ///
+    /// This combines two upper bounds, such as two max sizes.
+    /// A dimension that can't be resolved sets no bound and is ignored; if neither can be resolved,
+    /// the dimension of `self` is kept.
+    #[must_use]
+    pub fn min_resolved(self, other: Size<Dimension>, parent: Size<Option<f32>>) -> Size<Dimension> {
+        self.combine_resolved(other, parent, f32::min)
+    }
+
+    /// Resolves both sizes against `parent`, and combines the values of each axis with `pick`
+    fn combine_resolved(
+        self,
+        other: Size<Dimension>,
+        parent: Size<Option<f32>>,
+        pick: fn(f32, f32) -> f32,
+    ) -> Size<Dimension> {
+        let combine = |a: Dimension, b: Dimension, parent: Option<f32>| match (
+            a.maybe_resolve(parent),
+            b.maybe_resolve(parent),
+        ) {
+            (Some(a), Some(b)) => Dimension::Points(pick(a, b)),
+            (Some(value), None) | (None, Some(value)) => Dimension::Points(value),
+            (None, None) => a,
+        };
+        Size {
+            width: combine(self.width, other.width, parent.width),
+            height: combine(self.height, other.height, parent.height),
+        }
+    }
+
     /// Generates a [`Size<Dimension>`] using [`Dimension::Auto`] in both width and height
     pub const AUTO: Size<Dimension> = Self { width: Dimension::Auto, height: Dimension::Auto };
 
@@ -263,7 +553,7 @@ impl Size<Dimension> {
 /// A 2-dimensional coordinate.
 ///
 /// When used in association with a [`Rect`], represents the bottom-left corner.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Point<T> {
     /// The x-coordinate
     pub x: T,
@@ -271,7 +561,477 @@ pub struct Point<T> {
     pub y: T,
 }
 
+impl<T> From<(T, T)> for Point<T> {
+    /// Creates a [`Point`] from an `(x, y)` tuple
+    fn from((x, y): (T, T)) -> Self {
+        Point { x, y }
+    }
+}
+
 impl Point<f32> {
     /// A [`Point`] with values (0,0), representing the origin
     pub const ZERO: Point<f32> = Self { x: 0.0, y: 0.0 };
+
+    /// Are both coordinates finite (neither infinite nor `NaN`)?
+    #[must_use]
+    pub fn is_finite(self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+
+    /// Replaces a coordinate that is infinite or `NaN` with `0.0`
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Point { x: sanitize_f32(self.x), y: sanitize_f32(self.y) }
+    }
+
+    /// Linearly interpolates the coordinates between `self` (at `t = 0.0`) and `other` (at `t = 1.0`)
+    #[must_use]
+    pub fn lerp(self, other: Point<f32>, t: f32) -> Point<f32> {
+        Point { x: lerp_f32(self.x, other.x, t), y: lerp_f32(self.y, other.y, t) }
+    }
+
+    /// Rounds both coordinates to the nearest whole number, with halfway values rounded away from zero
+    #[must_use]
+    pub fn round(self) -> Point<f32> {
+        Point { x: round(self.x), y: round(self.y) }
+    }
+
+    /// Rounds both coordinates down to the nearest whole number
+    #[must_use]
+    pub fn floor(self) -> Point<f32> {
+        Point { x: floor(self.x), y: floor(self.y) }
+    }
+
+    /// Rounds both coordinates up to the nearest whole number
+    #[must_use]
+    pub fn ceil(self) -> Point<f32> {
+        Point { x: ceil(self.x), y: ceil(self.y) }
+    }
+
+    /// Orders points top-to-bottom, and then left-to-right within the same row
+    ///
+    /// This is the reading order of a left-to-right, top-to-bottom layout, and gives a deterministic spatial sort order.
+    /// Coordinates are compared with [`f32::total_cmp`], so `NaN` values are ordered consistently too.
+    #[must_use]
+    pub fn cmp_row_major(&self, other: &Point<f32>) -> Ordering {
+        self.y.total_cmp(&other.y).then_with(|| self.x.total_cmp(&other.x))
+    }
+
+    /// Orders points left-to-right, and then top-to-bottom within the same column
+    ///
+    /// Coordinates are compared with [`f32::total_cmp`], so `NaN` values are ordered consistently too.
+    #[must_use]
+    pub fn cmp_column_major(&self, other: &Point<f32>) -> Ordering {
+        self.x.total_cmp(&other.x).then_with(|| self.y.total_cmp(&other.y))
+    }
+}
+
+/// Returns the bit pattern of `value`, treating all `NaN`s as equal and `-0.0` as equal to `0.0`
+fn f32_to_bits(value: f32) -> u32 {
+    if value.is_nan() {
+        f32::NAN.to_bits()
+    } else if value == 0.0 {
+        0.0f32.to_bits()
+    } else {
+        value.to_bits()
+    }
+}
+
+/// Linearly interpolates between `a` and `b`, returning exactly `b` when `t` is `1.0`
+fn lerp_f32(a: f32, b: f32, t: f32) -> f32 {
+    a * (1.0 - t) + b * t
+}
+
+/// Returns `value` if it is finite, and `0.0` otherwise
+fn sanitize_f32(value: f32) -> f32 {
+    if value.is_finite() {
+        value
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod ordering {
+        use crate::geometry::Point;
+        use core::cmp::Ordering;
+
+        #[test]
+        fn row_major() {
+            let top_left = Point { x: 0.0, y: 0.0 };
+            let top_right = Point { x: 10.0, y: 0.0 };
+            let bottom_left = Point { x: 0.0, y: 10.0 };
+            let bottom_right = Point { x: 10.0, y: 10.0 };
+
+            let mut points = [bottom_right, top_right, bottom_left, top_left];
+            points.sort_by(Point::cmp_row_major);
+            assert_eq!(points, [top_left, top_right, bottom_left, bottom_right]);
+            assert_eq!(top_left.cmp_row_major(&top_left), Ordering::Equal);
+        }
+
+        #[test]
+        fn column_major() {
+            let top_left = Point { x: 0.0, y: 0.0 };
+            let top_right = Point { x: 10.0, y: 0.0 };
+            let bottom_left = Point { x: 0.0, y: 10.0 };
+            let bottom_right = Point { x: 10.0, y: 10.0 };
+
+            let mut points = [bottom_right, top_right, bottom_left, top_left];
+            points.sort_by(Point::cmp_column_major);
+            assert_eq!(points, [top_left, bottom_left, top_right, bottom_right]);
+        }
+    }
+
+    mod hit_testing {
+        use crate::geometry::{Point, Rect};
+
+        #[test]
+        fn contains_point() {
+            let rect = Rect::new(0.0, 10.0, 0.0, 20.0);
+            assert!(rect.contains_point(Point { x: 5.0, y: 5.0 }));
+            assert!(rect.contains_point(Point { x: 0.0, y: 0.0 }));
+            assert!(!rect.contains_point(Point { x: 10.0, y: 5.0 }));
+            assert!(!rect.contains_point(Point { x: 5.0, y: 20.0 }));
+            assert!(!rect.contains_point(Point { x: -1.0, y: 5.0 }));
+        }
+
+        #[test]
+        fn overlapping() {
+            let a = Rect::new(0.0, 10.0, 0.0, 10.0);
+            let b = Rect::new(5.0, 15.0, -5.0, 5.0);
+            assert!(a.intersects(&b));
+            assert!(b.intersects(&a));
+            assert_eq!(a.intersection(&b), Some(Rect::new(5.0, 10.0, 0.0, 5.0)));
+
+            let inner = Rect::new(2.0, 3.0, 2.0, 3.0);
+            assert_eq!(a.intersection(&inner), Some(inner));
+        }
+
+        #[test]
+        fn touching() {
+            let a = Rect::new(0.0, 10.0, 0.0, 10.0);
+            let right = Rect::new(10.0, 20.0, 0.0, 10.0);
+            let corner = Rect::new(10.0, 20.0, 10.0, 20.0);
+            assert!(!a.intersects(&right));
+            assert_eq!(a.intersection(&right), None);
+            assert!(!a.intersects(&corner));
+        }
+
+        #[test]
+        fn disjoint() {
+            let a = Rect::new(0.0, 10.0, 0.0, 10.0);
+            let b = Rect::new(20.0, 30.0, 20.0, 30.0);
+            assert!(!a.intersects(&b));
+            assert_eq!(b.intersection(&a), None);
+        }
+    }
+
+    mod logical {
+        use crate::geometry::Rect;
+        use crate::style::FlexDirection;
+
+        #[test]
+        fn row() {
+            let rect = Rect::new(1.0, 2.0, 3.0, 4.0);
+            assert_eq!(rect.logical(FlexDirection::Row), (1.0, 2.0, 3.0, 4.0));
+            assert_eq!(rect.logical(FlexDirection::RowReverse), (1.0, 2.0, 3.0, 4.0));
+        }
+
+        #[test]
+        fn column() {
+            let rect = Rect::new(1.0, 2.0, 3.0, 4.0);
+            assert_eq!(rect.logical(FlexDirection::Column), (3.0, 4.0, 1.0, 2.0));
+            assert_eq!(rect.logical(FlexDirection::ColumnReverse), (3.0, 4.0, 1.0, 2.0));
+        }
+    }
+
+    mod to_bits {
+        use crate::geometry::{Rect, Size};
+        use std::collections::HashMap;
+
+        #[test]
+        fn size_can_key_a_map() {
+            let mut cache = HashMap::new();
+            cache.insert(Size::new(10.0, 20.0).to_bits(), 1);
+            cache.insert(Size { width: Some(10.0), height: None }.to_bits(), 2);
+
+            assert_eq!(cache.get(&Size::new(10.0, 20.0).to_bits()), Some(&1));
+            assert_eq!(cache.get(&Size { width: Some(10.0), height: None }.to_bits()), Some(&2));
+            assert_eq!(cache.get(&Size::new(10.0, 21.0).to_bits()), None);
+            assert_eq!(cache.get(&Size::NONE.to_bits()), None);
+        }
+
+        #[test]
+        fn nan_and_negative_zero_are_consistent() {
+            let nan = Size { width: f32::NAN, height: -0.0 }.to_bits();
+            let other_nan = Size { width: -f32::NAN, height: 0.0 }.to_bits();
+            assert_eq!(nan, other_nan);
+
+            let mut cache = HashMap::new();
+            cache.insert(nan, 1);
+            assert_eq!(cache.get(&other_nan), Some(&1));
+        }
+
+        #[test]
+        fn rect_can_key_a_map() {
+            let mut cache = HashMap::new();
+            cache.insert(Rect::new(1.0, 2.0, 3.0, 4.0).to_bits(), 1);
+
+            assert_eq!(cache.get(&Rect::new(1.0, 2.0, 3.0, 4.0).to_bits()), Some(&1));
+            assert_eq!(cache.get(&Rect::new(4.0, 3.0, 2.0, 1.0).to_bits()), None);
+        }
+    }
+
+    mod maybe_apply_aspect_ratio {
+        use crate::geometry::Size;
+
+        #[test]
+        fn fills_missing_height() {
+            let size = Size { width: Some(100.0), height: None };
+            assert_eq!(size.maybe_apply_aspect_ratio(Some(2.0)), Size::new(100.0, 50.0));
+        }
+
+        #[test]
+        fn fills_missing_width() {
+            let size = Size { width: None, height: Some(50.0) };
+            assert_eq!(size.maybe_apply_aspect_ratio(Some(2.0)), Size::new(100.0, 50.0));
+        }
+
+        #[test]
+        fn keeps_both_axes() {
+            let size = Size::new(100.0, 100.0);
+            assert_eq!(size.maybe_apply_aspect_ratio(Some(2.0)), size);
+        }
+
+        #[test]
+        fn keeps_neither_axis() {
+            assert_eq!(Size::NONE.maybe_apply_aspect_ratio(Some(2.0)), Size::NONE);
+        }
+
+        #[test]
+        fn without_ratio() {
+            let width_only = Size { width: Some(100.0), height: None };
+            let height_only = Size { width: None, height: Some(50.0) };
+            assert_eq!(width_only.maybe_apply_aspect_ratio(None), width_only);
+            assert_eq!(height_only.maybe_apply_aspect_ratio(None), height_only);
+        }
+    }
+
+    mod lerp {
+        use crate::geometry::{Point, Rect, Size};
+
+        #[test]
+        fn size() {
+            let from = Size { width: 10.0, height: 20.0 };
+            let to = Size { width: 30.0, height: 0.0 };
+            assert_eq!(from.lerp(to, 0.0), from);
+            assert_eq!(from.lerp(to, 0.5), Size { width: 20.0, height: 10.0 });
+            assert_eq!(from.lerp(to, 1.0), to);
+        }
+
+        #[test]
+        fn rect() {
+            let from = Rect { start: 0.0, end: 10.0, top: -4.0, bottom: 8.0 };
+            let to = Rect { start: 10.0, end: 10.0, top: 4.0, bottom: 0.0 };
+            assert_eq!(from.lerp(to, 0.0), from);
+            assert_eq!(from.lerp(to, 0.5), Rect { start: 5.0, end: 10.0, top: 0.0, bottom: 4.0 });
+            assert_eq!(from.lerp(to, 1.0), to);
+        }
+
+        #[test]
+        fn point() {
+            let from = Point { x: 0.1, y: 100.0 };
+            let to = Point { x: 0.7, y: -100.0 };
+            assert_eq!(from.lerp(to, 0.0), from);
+            assert_eq!(from.lerp(to, 0.5), Point { x: 0.4, y: 0.0 });
+            assert_eq!(from.lerp(to, 1.0), to);
+        }
+    }
+
+    mod rounding {
+        use crate::geometry::{Point, Rect, Size};
+
+        #[test]
+        fn size() {
+            let size = Size { width: 10.5, height: -2.25 };
+            assert_eq!(size.round(), Size { width: 11.0, height: -2.0 });
+            assert_eq!(size.floor(), Size { width: 10.0, height: -3.0 });
+            assert_eq!(size.ceil(), Size { width: 11.0, height: -2.0 });
+        }
+
+        #[test]
+        fn point() {
+            let point = Point { x: -0.5, y: 3.7 };
+            assert_eq!(point.round(), Point { x: -1.0, y: 4.0 });
+            assert_eq!(point.floor(), Point { x: -1.0, y: 3.0 });
+            assert_eq!(point.ceil(), Point { x: 0.0, y: 4.0 });
+        }
+
+        #[test]
+        fn rect() {
+            let rect = Rect { start: 0.4, end: 1.6, top: -1.5, bottom: 2.0 };
+            assert_eq!(rect.round(), Rect { start: 0.0, end: 2.0, top: -2.0, bottom: 2.0 });
+            assert_eq!(rect.floor(), Rect { start: 0.0, end: 1.0, top: -2.0, bottom: 2.0 });
+            assert_eq!(rect.ceil(), Rect { start: 1.0, end: 2.0, top: -1.0, bottom: 2.0 });
+        }
+    }
+
+    mod scale {
+        use crate::geometry::Size;
+
+        const LANDSCAPE: Size<f32> = Size { width: 400.0, height: 200.0 };
+        const PORTRAIT_BOX: Size<f32> = Size { width: 100.0, height: 300.0 };
+
+        #[test]
+        fn fit() {
+            assert_eq!(LANDSCAPE.scale_to_fit(PORTRAIT_BOX), Size { width: 100.0, height: 50.0 });
+            assert_eq!(LANDSCAPE.scale_to_fit(LANDSCAPE), LANDSCAPE);
+        }
+
+        #[test]
+        fn cover() {
+            assert_eq!(LANDSCAPE.scale_to_cover(PORTRAIT_BOX), Size { width: 600.0, height: 300.0 });
+            assert_eq!(LANDSCAPE.scale_to_cover(LANDSCAPE), LANDSCAPE);
+        }
+
+        #[test]
+        fn degenerate() {
+            let empty = Size { width: 0.0, height: 10.0 };
+            assert_eq!(empty.scale_to_fit(PORTRAIT_BOX), Size::ZERO);
+            assert_eq!(empty.scale_to_cover(PORTRAIT_BOX), Size::ZERO);
+        }
+    }
+
+    mod combine_resolved {
+        use crate::geometry::Size;
+        use crate::style::Dimension;
+
+        const PARENT: Size<Option<f32>> = Size { width: Some(200.0), height: Some(100.0) };
+
+        #[test]
+        fn max() {
+            let points = Size::from_points(50.0, 50.0);
+            let percent = Size::from_percent(0.2, 0.8);
+            assert_eq!(points.max_resolved(percent, PARENT), Size::from_points(50.0, 80.0));
+            assert_eq!(percent.max_resolved(points, PARENT), Size::from_points(50.0, 80.0));
+        }
+
+        #[test]
+        fn min() {
+            let points = Size::from_points(50.0, 50.0);
+            let percent = Size::from_percent(0.2, 0.8);
+            assert_eq!(points.min_resolved(percent, PARENT), Size::from_points(40.0, 50.0));
+        }
+
+        #[test]
+        fn unresolvable() {
+            let percent = Size::from_percent(0.5, 0.5);
+            let mixed = Size { width: Dimension::Auto, height: Dimension::Points(10.0) };
+            assert_eq!(percent.max_resolved(mixed, PARENT), Size::from_points(100.0, 50.0));
+            assert_eq!(
+                percent.max_resolved(mixed, Size::NONE),
+                Size { width: Dimension::Percent(0.5), height: Dimension::Points(10.0) }
+            );
+            assert_eq!(Size::AUTO.min_resolved(Size::UNDEFINED, PARENT), Size::AUTO);
+        }
+    }
+
+    mod transpose {
+        use crate::geometry::{Rect, Size};
+
+        #[test]
+        fn rect() {
+            let rect = Rect { start: 1, end: 2, top: 3, bottom: 4 };
+            assert_eq!(rect.swap_axes(), Rect { start: 3, end: 4, top: 1, bottom: 2 });
+            assert_eq!(rect.swap_axes().swap_axes(), rect);
+        }
+
+        #[test]
+        fn size() {
+            let size = Size { width: 1, height: 2 };
+            assert_eq!(size.transpose(), Size { width: 2, height: 1 });
+            assert_eq!(size.transpose().transpose(), size);
+        }
+    }
+
+    mod default {
+        use crate::geometry::Rect;
+        use crate::style::Dimension;
+
+        #[test]
+        fn rect_f32() {
+            assert_eq!(Rect::<f32>::default(), Rect::ZERO);
+        }
+
+        #[test]
+        fn rect_option() {
+            assert_eq!(Rect::<Option<f32>>::default(), Rect { start: None, end: None, top: None, bottom: None });
+        }
+
+        #[test]
+        fn rect_dimension() {
+            assert_eq!(Rect::<Dimension>::default(), Rect::UNDEFINED);
+        }
+    }
+
+    mod from_tuple {
+        use crate::geometry::{Point, Rect, Size};
+        use crate::style::Dimension;
+
+        #[test]
+        fn point() {
+            assert_eq!(Point::from((1.0, 2.0)), Point { x: 1.0, y: 2.0 });
+        }
+
+        #[test]
+        fn size() {
+            assert_eq!(Size::from((1.0, 2.0)), Size { width: 1.0, height: 2.0 });
+            assert_eq!(
+                Size::from((Dimension::Points(1.0), Dimension::Auto)),
+                Size { width: Dimension::Points(1.0), height: Dimension::Auto }
+            );
+        }
+
+        #[test]
+        fn rect() {
+            assert_eq!(Rect::from((1.0, 2.0, 3.0, 4.0)), Rect { start: 1.0, end: 2.0, top: 3.0, bottom: 4.0 });
+            let rect: Rect<Option<f32>> = (Some(1.0), None, Some(3.0), None).into();
+            assert_eq!(rect, Rect { start: Some(1.0), end: None, top: Some(3.0), bottom: None });
+        }
+    }
+
+    mod sanitize {
+        use crate::geometry::{Point, Rect, Size};
+
+        #[test]
+        fn size() {
+            assert!(Size { width: 1.0, height: -2.0 }.is_finite());
+            assert!(!Size { width: f32::NAN, height: 2.0 }.is_finite());
+            assert!(!Size { width: 1.0, height: f32::INFINITY }.is_finite());
+
+            let size = Size { width: f32::NAN, height: f32::NEG_INFINITY }.sanitize();
+            assert_eq!(size, Size::ZERO);
+            let size = Size { width: 1.0, height: f32::INFINITY }.sanitize();
+            assert_eq!(size, Size { width: 1.0, height: 0.0 });
+        }
+
+        #[test]
+        fn rect() {
+            assert!(Rect::new(1.0, 2.0, 3.0, 4.0).is_finite());
+            assert!(!Rect::new(1.0, 2.0, f32::NAN, 4.0).is_finite());
+
+            let rect = Rect::new(f32::INFINITY, 2.0, f32::NAN, 4.0).sanitize();
+            assert_eq!(rect, Rect::new(0.0, 2.0, 0.0, 4.0));
+        }
+
+        #[test]
+        fn point() {
+            assert!(Point { x: 1.0, y: 2.0 }.is_finite());
+            assert!(!Point { x: f32::NAN, y: 2.0 }.is_finite());
+
+            let point = Point { x: f32::NAN, y: f32::INFINITY }.sanitize();
+            assert_eq!(point, Point::ZERO);
+        }
+    }
 }
@@ -1,9 +1,12 @@
 //! Final and cached data structures that represent the high-level UI layout
 
-use crate::geometry::{Point, Size};
+use crate::geometry::{Point, Rect, Size};
 
 /// The final result of a layout algorithm for a single [`Node`](crate::node::Node).
-#[derive(Copy, Debug, Clone)]
+///
+/// When rounding is enabled, every value is rounded to a whole pixel on its own. As a result the rounded border and
+/// padding of a node whose edges are fractional can add up to one pixel more than its rounded size on each axis.
+#[derive(Copy, Debug, Clone, PartialEq)]
 pub struct Layout {
     /// The relative ordering of the node
     ///
@@ -14,18 +17,39 @@ pub struct Layout {
     pub size: Size<f32>,
     /// The bottom-left corner of the node
     pub location: Point<f32>,
+    /// The resolved margin of the node
+    ///
+    /// Percentages are resolved against the size of the parent, and `auto` margins contain the space they absorbed.
+    pub margin: Rect<f32>,
+    /// The resolved border widths of the node
+    pub border: Rect<f32>,
+    /// The resolved padding of the node
+    pub padding: Rect<f32>,
+    /// The size of the area spanned by the children of the node, relative to its origin
+    ///
+    /// This extends to the far edges of every child, including those that overflow the node and the overflow of the
+    /// children themselves, so it can be larger than [`Layout::size`]. It is zero for nodes without children.
+    pub content_size: Size<f32>,
 }
 
 impl Layout {
     /// Creates a new [`Layout`] struct with zero size positioned at the origin
     #[must_use]
     pub(crate) fn new() -> Self {
-        Self { order: 0, size: Size::ZERO, location: Point::ZERO }
+        Self {
+            order: 0,
+            size: Size::ZERO,
+            location: Point::ZERO,
+            margin: Rect::ZERO,
+            border: Rect::ZERO,
+            padding: Rect::ZERO,
+            content_size: Size::ZERO,
+        }
     }
 }
 
 /// Cached intermediate layout results
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub(crate) struct Cache {
     /// The initial cached size of the node itself
     pub(crate) node_size: Size<Option<f32>>,
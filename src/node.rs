@@ -3,12 +3,15 @@
 //! Layouts are composed of multiple nodes, which live in a forest-like data structure.
 use crate::error;
 use crate::forest::Forest;
-use crate::geometry::Size;
+use crate::geometry::{Point, Size};
 use crate::layout::Layout;
+use crate::resolve::MaybeResolve;
 use crate::style::FlexboxLayout;
 #[cfg(any(feature = "std", feature = "alloc"))]
+use crate::style::{Display, PositionType};
+#[cfg(any(feature = "std", feature = "alloc"))]
 use crate::sys::Box;
-use crate::sys::{new_map_with_capacity, ChildrenVec, Map, Vec};
+use crate::sys::{new_map_with_capacity, round, ChildrenVec, Map, Vec};
 use core::sync::atomic::{AtomicUsize, Ordering};
 
 /// Internal node id.
@@ -46,6 +49,9 @@ pub trait Measurable: Send + Sync + Fn(Size<Option<f32>>) -> Size<f32> {}
 impl<F: Send + Sync + Fn(Size<Option<f32>>) -> Size<f32>> Measurable for F {}
 
 /// A function that can be used to compute the intrinsic size of a node
+///
+/// The returned size must be finite and non-negative. Debug builds panic when it isn't, naming the measured node,
+/// while release builds replace the offending values with zero.
 pub enum MeasureFunc {
     /// Stores an unboxed function
     Raw(fn(Size<Option<f32>>) -> Size<f32>),
@@ -67,6 +73,84 @@ pub struct Node {
     local: Id,
 }
 
+impl Node {
+    /// The index of this node within its [`Taffy`] instance
+    ///
+    /// Indices are assigned in creation order and are never reused, even after the node is removed.
+    /// This is the number shown when the node is displayed.
+    #[must_use]
+    pub fn index(self) -> usize {
+        self.local.0
+    }
+}
+
+impl core::fmt::Display for Node {
+    /// Formats the node as `Node#<index>`, where the index is unique within its [`Taffy`] instance
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Node#{}", self.local.0)
+    }
+}
+
+/// A serializable description of a tree of nodes, which can be built with [`Taffy::from_description`]
+///
+/// Children are nested directly inside their parent, so every description forms a well-formed tree.
+#[cfg(all(feature = "serde", any(feature = "std", feature = "alloc")))]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TreeDescription {
+    /// The style of this node
+    pub style: FlexboxLayout,
+    /// The descriptions of the children of this node, in order
+    pub children: Vec<TreeDescription>,
+}
+
+/// A problem with the styles of a node that was found by the last call to [`Taffy::compute_layout`]
+///
+/// Diagnostics don't prevent layout: they point out styles that had no effect, which usually indicates a mistake.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Diagnostic {
+    /// The percentage width of the [`Node`] couldn't be resolved, because its parent has no definite width
+    ///
+    /// The width was treated as [`Dimension::Auto`](crate::style::Dimension::Auto) instead.
+    UnresolvedPercentageWidth(Node),
+    /// The percentage height of the [`Node`] couldn't be resolved, because its parent has no definite height
+    ///
+    /// The height was treated as [`Dimension::Auto`](crate::style::Dimension::Auto) instead.
+    UnresolvedPercentageHeight(Node),
+}
+
+/// An owned, read-only copy of the layouts and children of every node in a [`Taffy`], made with [`Taffy::snapshot`]
+///
+/// A snapshot doesn't borrow the [`Taffy`] it was made from, and can be sent to and shared between threads,
+/// for example to render the results of a layout while the tree is being modified again.
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LayoutSnapshot {
+    /// The layout of each node, as of when the snapshot was made
+    layouts: Map<Node, Layout>,
+    /// The children of each node, in order
+    children: Map<Node, Vec<Node>>,
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl LayoutSnapshot {
+    /// Returns the layout of `node` relative to its parent, see [`Taffy::layout`]
+    pub fn layout(&self, node: Node) -> Result<&Layout, error::InvalidNode> {
+        self.layouts.get(&node).ok_or(error::InvalidNode(node))
+    }
+
+    /// Returns the children of `node`, see [`Taffy::children`]
+    pub fn children(&self, node: Node) -> Result<&[Node], error::InvalidNode> {
+        self.children.get(&node).map(Vec::as_slice).ok_or(error::InvalidNode(node))
+    }
+
+    /// Returns the number of nodes in the snapshot
+    #[must_use]
+    pub fn node_count(&self) -> usize {
+        self.layouts.len()
+    }
+}
+
 /// A forest of UI [`Nodes`](`Node`), suitable for UI layout
 pub struct Taffy {
     /// The ID of the root node
@@ -79,6 +163,16 @@ pub struct Taffy {
     ids_to_nodes: Map<NodeId, Node>,
     /// An efficient data structure that stores the node trees
     forest: Forest,
+    /// Should the computed layouts be rounded to whole pixels?
+    use_rounding: bool,
+    /// The canonical root set with [`Taffy::set_root`]
+    root: Option<Node>,
+    /// The root passed to the last call to [`Taffy::compute_layout`]
+    last_root: Option<Node>,
+    /// The available space and origin passed with [`Taffy::last_root`], while its stored layouts are still current
+    last_inputs: Option<(Size<Option<f32>>, Point<f32>)>,
+    /// A map from each portal node to the node it is positioned relative to
+    portal_anchors: Map<Node, Node>,
 }
 
 impl Default for Taffy {
@@ -104,6 +198,36 @@ impl Taffy {
             nodes_to_ids: new_map_with_capacity(capacity),
             ids_to_nodes: new_map_with_capacity(capacity),
             forest: Forest::with_capacity(capacity),
+            use_rounding: false,
+            root: None,
+            last_root: None,
+            last_inputs: None,
+            portal_anchors: new_map_with_capacity(capacity),
+        }
+    }
+
+    /// Enables rounding of the computed layouts to whole pixels
+    ///
+    /// Once enabled, every subsequent call to [`Taffy::compute_layout`] snaps the final sizes and locations
+    /// of all nodes to the nearest whole number. Rounding is disabled by default.
+    pub fn enable_rounding(&mut self) {
+        self.set_rounding(true);
+    }
+
+    /// Disables rounding of the computed layouts, so that they contain exact fractional values
+    ///
+    /// This is the default.
+    pub fn disable_rounding(&mut self) {
+        self.set_rounding(false);
+    }
+
+    /// Sets whether or not the computed layouts should be rounded
+    ///
+    /// Changing the mode invalidates all cached layouts, as they were computed under the previous mode.
+    fn set_rounding(&mut self, use_rounding: bool) {
+        if self.use_rounding != use_rounding {
+            self.use_rounding = use_rounding;
+            self.forest.mark_all_dirty();
         }
     }
 
@@ -159,13 +283,39 @@ impl Taffy {
         Ok(node)
     }
 
+    /// Creates a new [`Taffy`] containing the tree of nodes described by `description`
+    ///
+    /// Returns the new [`Taffy`] along with the root node of the tree.
+    #[cfg(all(feature = "serde", any(feature = "std", feature = "alloc")))]
+    pub fn from_description(description: &TreeDescription) -> Result<(Taffy, Node), error::InvalidNode> {
+        let mut taffy = Taffy::new();
+        let root = taffy.add_description(description)?;
+        Ok((taffy, root))
+    }
+
+    /// Adds the nodes described by `description`, and returns the root node of the new tree
+    #[cfg(all(feature = "serde", any(feature = "std", feature = "alloc")))]
+    fn add_description(&mut self, description: &TreeDescription) -> Result<Node, error::InvalidNode> {
+        let children = description
+            .children
+            .iter()
+            .map(|child| self.add_description(child))
+            .collect::<Result<Vec<_>, error::InvalidNode>>()?;
+        self.new_with_children(description.style, &children)
+    }
+
     /// Removes all nodes
     ///
-    /// All associated [`Id`] will be rendered invalid.
+    /// The allocated capacity is retained, so rebuilding a tree of the same size afterwards doesn't reallocate.
+    /// This makes it cheap to rebuild the whole tree every frame. Every previously created [`Node`] is invalid
+    /// afterwards, and will never refer to a node created after the clear.
     pub fn clear(&mut self) {
         self.nodes_to_ids.clear();
         self.ids_to_nodes.clear();
+        self.portal_anchors.clear();
         self.forest.clear();
+        self.root = None;
+        self.last_inputs = None;
     }
 
     /// Remove a specific [`Node`] from the tree
@@ -176,6 +326,12 @@ impl Taffy {
 
         self.nodes_to_ids.remove(&node);
         self.ids_to_nodes.remove(&id);
+        self.portal_anchors.remove(&node);
+        if self.root == Some(node) {
+            self.root = None;
+        }
+        // Removing a node does not mark its parents as dirty, so the last layout can no longer be trusted
+        self.last_inputs = None;
 
         if let Some(new_id) = self.forest.swap_remove(id) {
             let new = self.ids_to_nodes.remove(&new_id).unwrap();
@@ -186,6 +342,33 @@ impl Taffy {
         Ok(id)
     }
 
+    /// Returns the number of nodes in this [`Taffy`], whether or not they are attached to a tree
+    pub fn node_count(&self) -> usize {
+        self.forest.len()
+    }
+
+    /// Iterates over every [`Node`] in this [`Taffy`], regardless of the tree it belongs to
+    ///
+    /// Detached nodes and the nodes of every tree are included, in storage order (see [`Taffy::compact`]).
+    /// This is simpler than walking each tree for passes over all nodes, such as collecting statistics.
+    pub fn all_nodes(&self) -> impl Iterator<Item = Node> + '_ {
+        (0..self.forest.len()).map(|id| self.ids_to_nodes[&id])
+    }
+
+    /// Reorders the stored nodes so that each tree is laid out contiguously in depth-first order
+    ///
+    /// After many insertions and removals the nodes of a tree can be scattered through storage,
+    /// which hurts the locality of [`Taffy::compute_layout`]. Every [`Node`] remains valid after compaction.
+    pub fn compact(&mut self) {
+        let mut ids_to_nodes = new_map_with_capacity(self.forest.nodes.capacity());
+        for (old, new) in self.forest.compact() {
+            let node = *self.ids_to_nodes.get(&old).unwrap();
+            let _ = self.nodes_to_ids.insert(node, new);
+            let _ = ids_to_nodes.insert(new, node);
+        }
+        self.ids_to_nodes = ids_to_nodes;
+    }
+
     /// Sets the [`MeasureFunc`] of the associated node
     pub fn set_measure(&mut self, node: Node, measure: Option<MeasureFunc>) -> Result<(), error::InvalidNode> {
         let id = self.find_node(node)?;
@@ -203,6 +386,28 @@ impl Taffy {
         Ok(())
     }
 
+    /// Inserts a `child` [`Node`] under the supplied `parent` at `child_index`
+    ///
+    /// Children at or after `child_index` are shifted along. `child_index` may be equal to the number of children,
+    /// in which case the child is appended.
+    pub fn add_child_at_index(
+        &mut self,
+        parent: Node,
+        child_index: usize,
+        child: Node,
+    ) -> Result<(), error::InvalidChild> {
+        let node_id = self.find_node(parent).map_err(|e| error::InvalidChild::InvalidParentNode(e.0))?;
+        let child_id = self.find_node(child).map_err(|e| error::InvalidChild::InvalidChildNode(e.0))?;
+
+        let child_count = self.forest.children[node_id].len();
+        if child_index > child_count {
+            return Err(error::InvalidChild::ChildIndexOutOfBounds { parent, child_index, child_count });
+        }
+
+        self.forest.add_child_at_index(node_id, child_index, child_id);
+        Ok(())
+    }
+
     /// Directly sets the `children` of the supplied `parent`
     pub fn set_children(&mut self, parent: Node, children: &[Node]) -> Result<(), error::InvalidNode> {
         let node_id = self.find_node(parent)?;
@@ -249,6 +454,39 @@ impl Taffy {
         Ok(self.ids_to_nodes[&prev_id])
     }
 
+    /// Moves `child` from `old_parent` to `new_parent`, inserting it at `child_index` or appending it if that is `None`
+    ///
+    /// Every argument is checked before anything is changed, so on error the tree is left untouched.
+    /// When moving within the same parent, `child_index` is the position among the other children.
+    /// Both parents are marked as dirty.
+    pub fn move_child(
+        &mut self,
+        old_parent: Node,
+        child: Node,
+        new_parent: Node,
+        child_index: Option<usize>,
+    ) -> Result<(), error::InvalidChild> {
+        let old_parent_id = self.find_node(old_parent).map_err(|e| error::InvalidChild::InvalidParentNode(e.0))?;
+        let new_parent_id = self.find_node(new_parent).map_err(|e| error::InvalidChild::InvalidParentNode(e.0))?;
+        let child_id = self.find_node(child).map_err(|e| error::InvalidChild::InvalidChildNode(e.0))?;
+
+        let old_index = self.forest.children[old_parent_id]
+            .iter()
+            .position(|n| *n == child_id)
+            .ok_or(error::InvalidChild::NotAChild { parent: old_parent, child })?;
+
+        // The child is detached first, so it doesn't count towards the children of its new parent
+        let child_count = self.forest.children[new_parent_id].len() - usize::from(old_parent_id == new_parent_id);
+        let child_index = child_index.unwrap_or(child_count);
+        if child_index > child_count {
+            return Err(error::InvalidChild::ChildIndexOutOfBounds { parent: new_parent, child_index, child_count });
+        }
+
+        self.forest.remove_child_at_index(old_parent_id, old_index);
+        self.forest.add_child_at_index(new_parent_id, child_index, child_id);
+        Ok(())
+    }
+
     /// Replaces the child at the given `child_index` from the `parent` node with the new `child` node
     ///
     /// The child is not removed from the forest entirely, it is simply no longer attached to its previous parent.
@@ -299,14 +537,66 @@ impl Taffy {
         Ok(self.forest.children[id].iter().map(|child| self.ids_to_nodes[child]).collect())
     }
 
+    /// Clears `buf` and fills it with the children that belong to the [`Parent`]
+    ///
+    /// Unlike [`Taffy::children`], this reuses the allocation of `buf`, which makes it suitable for hot loops.
+    pub fn children_into(&self, parent: Node, buf: &mut Vec<Node>) -> Result<(), error::InvalidNode> {
+        let id = self.find_node(parent)?;
+        buf.clear();
+        buf.extend(self.forest.children[id].iter().map(|child| self.ids_to_nodes[child]));
+        Ok(())
+    }
+
+    /// Returns the parents of the `child` [`Node`], in the order they were attached
+    ///
+    /// A node can be shared between several parents, so this may contain more than one entry.
+    pub fn parents(&self, child: Node) -> Result<Vec<Node>, error::InvalidNode> {
+        let id = self.find_node(child)?;
+        Ok(self.forest.parents[id].iter().map(|parent| self.ids_to_nodes[parent]).collect())
+    }
+
+    /// Returns the first parent of the `child` [`Node`], or `None` if it is a root
+    ///
+    /// This is a convenience for trees where each node has a single parent; see [`Taffy::parents`] otherwise.
+    pub fn parent(&self, child: Node) -> Result<Option<Node>, error::InvalidNode> {
+        let id = self.find_node(child)?;
+        Ok(self.forest.parents[id].first().map(|parent| self.ids_to_nodes[parent]))
+    }
+
     /// Sets the [`Style`] of the provided `node`
     pub fn set_style(&mut self, node: Node, style: FlexboxLayout) -> Result<(), error::InvalidNode> {
         let id = self.find_node(node)?;
-        self.forest.nodes[id].style = style;
-        self.forest.mark_dirty(id);
+        let old_style = core::mem::replace(&mut self.forest.nodes[id].style, style);
+        self.mark_style_changed(id, &old_style);
+        Ok(())
+    }
+
+    /// Updates the [`Style`] of the provided `node` in place, using the provided closure
+    ///
+    /// The node is only marked as dirty if the closure actually changed its style.
+    pub fn update_style(&mut self, node: Node, f: impl FnOnce(&mut FlexboxLayout)) -> Result<(), error::InvalidNode> {
+        let id = self.find_node(node)?;
+        let old_style = self.forest.nodes[id].style;
+        f(&mut self.forest.nodes[id].style);
+        if self.forest.nodes[id].style != old_style {
+            self.mark_style_changed(id, &old_style);
+        }
         Ok(())
     }
 
+    /// Invalidates the layouts affected by changing the style of `id` away from `old_style`
+    ///
+    /// If the change can only affect how the node is laid out by its parent, the node's own cache is kept.
+    /// Otherwise all of it is cleared: each slot holds both the width and the height of a result,
+    /// so a change along one axis can't safely keep the slots of the other.
+    fn mark_style_changed(&mut self, id: NodeId, old_style: &FlexboxLayout) {
+        if self.forest.nodes[id].style.has_same_inner_layout(old_style) {
+            self.forest.mark_dirty_preserving_cache(id);
+        } else {
+            self.forest.mark_dirty(id);
+        }
+    }
+
     /// Gets the [`Style`] of the provided `node`
     pub fn style(&self, node: Node) -> Result<&FlexboxLayout, error::InvalidNode> {
         let id = self.find_node(node)?;
@@ -319,6 +609,39 @@ impl Taffy {
         Ok(&self.forest.nodes[id].layout)
     }
 
+    /// Copies the layouts and children of every node into a [`LayoutSnapshot`] that doesn't borrow this [`Taffy`]
+    ///
+    /// The snapshot holds the layouts as they are now: it isn't updated by later calls to [`Taffy::compute_layout`].
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn snapshot(&self) -> LayoutSnapshot {
+        let mut layouts = new_map_with_capacity(self.forest.len());
+        let mut children = new_map_with_capacity(self.forest.len());
+        for (id, node) in &self.ids_to_nodes {
+            layouts.insert(*node, self.forest.nodes[*id].layout);
+            children.insert(*node, self.forest.children[*id].iter().map(|child| self.ids_to_nodes[child]).collect());
+        }
+        LayoutSnapshot { layouts, children }
+    }
+
+    /// Returns the scrollable overflow of this node, as of the last layout
+    ///
+    /// This is at least the node's own size, and grows to contain the far edges of any descendants that overflow it.
+    /// Relative offsets move a child's box, so they extend the overflow even though they don't move the child's siblings.
+    pub fn scroll_size(&self, node: Node) -> Result<Size<f32>, error::InvalidNode> {
+        let layout = self.layout(node)?;
+        Ok(Size {
+            width: layout.size.width.max(layout.content_size.width),
+            height: layout.size.height.max(layout.content_size.height),
+        })
+    }
+
+    /// Returns the size of the area spanned by the children of this node, as of the last layout
+    ///
+    /// See [`Layout::content_size`].
+    pub fn content_size(&self, node: Node) -> Result<Size<f32>, error::InvalidNode> {
+        Ok(self.layout(node)?.content_size)
+    }
+
     /// Marks the layout computation of this node and its children as outdated
     pub fn mark_dirty(&mut self, node: Node) -> Result<(), error::InvalidNode> {
         let id = self.find_node(node)?;
@@ -326,25 +649,437 @@ impl Taffy {
         Ok(())
     }
 
+    /// Positions `node` relative to the box of `anchor` instead of that of its parent, like a tooltip or a menu
+    ///
+    /// The node stays a child of its parent, but takes no space in it, just like an absolutely positioned node.
+    /// Its `start` and `top` [`position`](FlexboxLayout::position) insets offset it from the top-left corner of `anchor`,
+    /// or if those are not set, its `end` and `bottom` insets offset it from the opposite corner;
+    /// percentages are resolved against the size of `anchor`.
+    /// Its [`Layout::location`] remains relative to its parent, as for every other node.
+    ///
+    /// The anchor must be laid out by the same call to [`Taffy::compute_layout`], or by an earlier one if it belongs to
+    /// another tree, and must not itself be inside a portal.
+    /// If the anchor is removed, the node is left wherever its parent placed it.
+    pub fn set_portal_anchor(&mut self, node: Node, anchor: Node) -> Result<(), error::InvalidNode> {
+        let id = self.find_node(node)?;
+        self.find_node(anchor)?;
+
+        let _ = self.portal_anchors.insert(node, anchor);
+        self.forest.nodes[id].is_portal = true;
+        self.forest.mark_dirty(id);
+        Ok(())
+    }
+
+    /// Positions `node` relative to its parent again, undoing [`Taffy::set_portal_anchor`]
+    ///
+    /// Returns the anchor that the node was positioned relative to, if any.
+    pub fn clear_portal_anchor(&mut self, node: Node) -> Result<Option<Node>, error::InvalidNode> {
+        let id = self.find_node(node)?;
+
+        let anchor = self.portal_anchors.remove(&node);
+        if anchor.is_some() {
+            self.forest.nodes[id].is_portal = false;
+            self.forest.mark_dirty(id);
+        }
+        Ok(anchor)
+    }
+
+    /// Freezes the layout of this node and its descendants
+    ///
+    /// While frozen, [`Taffy::compute_layout`] reuses the stored size of the node and does not recurse into it,
+    /// even if the node has been marked as dirty. The node can still be moved by its parent.
+    /// Only freeze nodes that have already been laid out, and whose size doesn't depend on their parent:
+    /// otherwise the frozen sizes will be stale.
+    pub fn freeze_subtree(&mut self, node: Node) -> Result<(), error::InvalidNode> {
+        let id = self.find_node(node)?;
+        self.forest.nodes[id].is_frozen = true;
+        Ok(())
+    }
+
+    /// Unfreezes a node previously frozen with [`Taffy::freeze_subtree`], so that its layout is recomputed
+    pub fn unfreeze_subtree(&mut self, node: Node) -> Result<(), error::InvalidNode> {
+        let id = self.find_node(node)?;
+        self.forest.nodes[id].is_frozen = false;
+        self.forest.mark_dirty(id);
+        Ok(())
+    }
+
+    /// Returns the min-content and max-content sizes of this node
+    ///
+    /// These are the sizes the node takes when laid out with no available space and with unlimited space respectively.
+    /// The results are cached on the node until it, or one of its descendants, is marked as dirty.
+    ///
+    /// A [`MeasureFunc`] is only passed the node's own size and not the available space, so it can't wrap its
+    /// content any further without space: the min-content size of a measured leaf is its max-content size.
+    pub fn intrinsic_sizes(&mut self, node: Node) -> Result<(Size<f32>, Size<f32>), error::InvalidNode> {
+        let id = self.find_node(node)?;
+        let sizes = self.forest.intrinsic_sizes(id);
+        self.check_measurements();
+        Ok(sizes)
+    }
+
+    /// Measures `node` again for the given known dimensions, without laying out the rest of its tree
+    ///
+    /// The [`MeasureFunc`] of a leaf is called with `available`, even if a result for it was already cached,
+    /// and the result replaces the cached one so that the next layout reuses it. When both dimensions are given,
+    /// they are the size and nothing is measured. Other nodes are sized as flex containers.
+    /// This is useful to react to a single leaf being resized interactively, such as a text box.
+    /// The stored [`Layout`] of `node` is not updated, nor is it marked as dirty.
+    pub fn remeasure(&mut self, node: Node, available: Size<Option<f32>>) -> Result<Size<f32>, error::InvalidNode> {
+        let id = self.find_node(node)?;
+        let size = self.forest.remeasure(id, available);
+        self.check_measurements();
+        Ok(size)
+    }
+
     /// Indicates whether the layout of this node (and its children) need to be recomputed
     pub fn dirty(&self, node: Node) -> Result<bool, error::InvalidNode> {
         let id = self.find_node(node)?;
         Ok(self.forest.nodes[id].is_dirty)
     }
 
+    /// Designates `node` as the canonical root, to be laid out by [`Taffy::compute_layout_root`]
+    ///
+    /// This saves tracking the root separately when this [`Taffy`] holds a single tree.
+    /// The root is unset if it is removed.
+    pub fn set_root(&mut self, node: Node) -> Result<(), error::InvalidNode> {
+        self.find_node(node)?;
+        self.root = Some(node);
+        Ok(())
+    }
+
+    /// Returns the canonical root set with [`Taffy::set_root`], if any
+    pub fn root(&self) -> Option<Node> {
+        self.root
+    }
+
+    /// Updates the stored layout of the canonical root and its children, see [`Taffy::compute_layout`]
+    ///
+    /// Returns the resulting size of the root, or `None` if no root has been set with [`Taffy::set_root`].
+    pub fn compute_layout_root(&mut self, size: Size<Option<f32>>) -> Result<Option<Size<f32>>, error::InvalidNode> {
+        match self.root {
+            Some(root) => self.compute_layout(root, size).map(Some),
+            None => Ok(None),
+        }
+    }
+
     /// Updates the stored layout of the provided `node` and its children
-    pub fn compute_layout(&mut self, node: Node, size: Size<Option<f32>>) -> Result<(), error::InvalidNode> {
+    ///
+    /// Returns the resulting size of `node`, which is equivalent to querying its [`Layout`] afterwards.
+    /// The results are rounded to whole pixels if rounding has been turned on with [`Taffy::enable_rounding`].
+    pub fn compute_layout(&mut self, node: Node, size: Size<Option<f32>>) -> Result<Size<f32>, error::InvalidNode> {
+        self.compute_layout_at(node, size, Point::ZERO)
+    }
+
+    /// Updates the stored layout of the provided `node` and its children, placing `node` at `origin`
+    ///
+    /// Locations remain relative to the parent, so only the location of `node` itself changes compared to
+    /// [`Taffy::compute_layout`], and every descendant is shifted along with it.
+    /// This is useful for embedding a tree at an offset within a larger canvas.
+    ///
+    /// If `node` was also the root of the previous layout, with the same `size` and `origin`, and nothing in its
+    /// tree has been marked as dirty since, the stored layouts are already up to date and are returned as is.
+    /// Only portals are positioned again, as their anchors may have been moved by the layout of another tree.
+    pub fn compute_layout_at(
+        &mut self,
+        node: Node,
+        size: Size<Option<f32>>,
+        origin: Point<f32>,
+    ) -> Result<Size<f32>, error::InvalidNode> {
+        let id = self.find_node(node)?;
+
+        let is_unchanged = self.last_root == Some(node) && self.last_inputs == Some((size, origin));
+        if is_unchanged && !self.forest.nodes[id].is_dirty {
+            // Anchors may belong to another tree, which can have moved since
+            self.position_portals();
+            return Ok(self.forest.nodes[id].layout.size);
+        }
+
+        self.forest.compute(id, size, origin, self.use_rounding);
+        self.check_measurements();
+        self.position_portals();
+        self.last_root = Some(node);
+        self.last_inputs = Some((size, origin));
+        Ok(self.forest.nodes[id].layout.size)
+    }
+
+    /// Panics if a [`MeasureFunc`] returned a size that is not finite or is negative during the last computation
+    ///
+    /// This only checks anything in debug builds: in release builds, such sizes are silently replaced with zero.
+    fn check_measurements(&mut self) {
+        #[cfg(debug_assertions)]
+        if let Some((id, size)) = self.forest.invalid_measurement.take() {
+            panic!(
+                "the measure function of {} returned {:?}, but measured sizes must be finite and non-negative",
+                self.ids_to_nodes[&id], size
+            );
+        }
+    }
+
+    /// Updates the stored layouts of the descendants of `node`, treating it as a root of exactly `size`
+    ///
+    /// This lays out a subtree on its own, such as a lazily populated part of a virtualized list,
+    /// without laying out its ancestors. The style size of `node` is ignored in favour of `size`.
+    /// Only the size of `node` itself is updated: the caller is responsible for positioning the subtree.
+    pub fn layout_subtree(&mut self, node: Node, size: Size<f32>) -> Result<(), error::InvalidNode> {
+        let id = self.find_node(node)?;
+        self.forest.compute_subtree(id, size, self.use_rounding);
+        self.check_measurements();
+        // The subtree may belong to the last root, whose stored layouts have now been overwritten
+        self.last_inputs = None;
+        Ok(())
+    }
+
+    /// Updates the stored layout of the provided `node` and its children, then calls `hook` with each final [`Layout`]
+    ///
+    /// The `hook` is called exactly once for `node` and each of its descendants, in depth-first pre-order
+    /// (a parent before its children, and children in order).
+    /// The layouts passed to it are final, so this can be used to stream results to a renderer without walking the tree again.
+    pub fn compute_layout_with_hook(
+        &mut self,
+        node: Node,
+        size: Size<Option<f32>>,
+        mut hook: impl FnMut(Node, &Layout),
+    ) -> Result<Size<f32>, error::InvalidNode> {
+        let root_size = self.compute_layout(node, size)?;
+        let id = self.find_node(node)?;
+        self.visit_layouts(id, &mut hook);
+        Ok(root_size)
+    }
+
+    /// Updates the stored layout of the provided `node` and its children, then copies their [`Layout`]s into `out`
+    ///
+    /// `out` is cleared, then filled with one layout per node of the subtree in depth-first pre-order
+    /// (a parent before its children, and children in order), so that `out[0]` is the layout of `node`.
+    /// This is the order in which [`Taffy::compute_layout_with_hook`] visits the nodes, so a caller can map each
+    /// [`Node`] to its index by walking the subtree with [`Taffy::children`] while counting.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn compute_layout_into(
+        &mut self,
+        node: Node,
+        size: Size<Option<f32>>,
+        out: &mut Vec<Layout>,
+    ) -> Result<Size<f32>, error::InvalidNode> {
+        let root_size = self.compute_layout(node, size)?;
+        let id = self.find_node(node)?;
+
+        out.clear();
+        self.visit_layouts(id, &mut |_, layout| out.push(*layout));
+
+        Ok(root_size)
+    }
+
+    /// Lays out `node` twice in a row, and panics if any [`Layout`] in its subtree differs between the two passes
+    ///
+    /// Nothing is changed in between, so a layout that isn't reproduced exactly points to state that leaks from one
+    /// pass into the next, such as a stale cache. The panic message names the first node that diverged, in
+    /// depth-first pre-order. This is meant for tests and debug builds, as it doubles the cost of the layout.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn assert_idempotent(&mut self, node: Node, size: Size<Option<f32>>) -> Result<(), error::InvalidNode> {
         let id = self.find_node(node)?;
-        self.forest.compute(id, size);
+
+        let mut first_pass = Vec::new();
+        self.compute_layout(node, size)?;
+        self.visit_layouts(id, &mut |node, layout| first_pass.push((node, *layout)));
+
+        // Skip the fast path for unchanged trees, so that the second pass really runs the algorithm again
+        self.last_inputs = None;
+        let mut second_pass = Vec::new();
+        self.compute_layout(node, size)?;
+        self.visit_layouts(id, &mut |node, layout| second_pass.push((node, *layout)));
+
+        for ((node, first), (_, second)) in first_pass.iter().zip(&second_pass) {
+            assert_eq!(first, second, "the layout of {} changed when it was computed again", node);
+        }
         Ok(())
     }
+
+    /// Returns the in-flow children of `container` grouped into the flex lines they were placed in by the last layout
+    ///
+    /// Lines are returned in the order they were created, and items within each line in the order of the children.
+    /// Children that are not laid out as flex items, such as absolutely positioned or hidden nodes, are omitted.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn flex_lines(&self, container: Node) -> Result<Vec<Vec<Node>>, error::InvalidNode> {
+        let id = self.find_node(container)?;
+
+        let mut lines: Vec<Vec<Node>> = Vec::new();
+        for child in &self.forest.children[id] {
+            if let Some(line) = self.forest.nodes[*child].flex_line {
+                if lines.len() <= line {
+                    lines.resize_with(line + 1, Vec::new);
+                }
+                lines[line].push(self.ids_to_nodes[child]);
+            }
+        }
+
+        Ok(lines)
+    }
+
+    /// Returns `root` and its descendants in the order they should be painted, from back to front
+    ///
+    /// Each node is painted before its descendants. The in-flow children of a node are painted first,
+    /// in ascending [`order`](FlexboxLayout::order) and then in source order, just like they are placed,
+    /// followed by its absolutely positioned and portal children in source order.
+    /// Hidden nodes are not painted, and neither are their descendants.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn paint_order(&self, root: Node) -> Result<Vec<Node>, error::InvalidNode> {
+        let id = self.find_node(root)?;
+        let mut nodes = Vec::new();
+        self.collect_paint_order(id, &mut nodes);
+        Ok(nodes)
+    }
+
+    /// Appends `id` and then, recursively, its visible descendants to `nodes` in paint order
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    fn collect_paint_order(&self, id: NodeId, nodes: &mut Vec<Node>) {
+        nodes.push(self.ids_to_nodes[&id]);
+
+        let mut children: Vec<NodeId> = self.forest.children[id]
+            .iter()
+            .copied()
+            .filter(|child| self.forest.nodes[*child].style.display != Display::None)
+            .collect();
+        // The sort is stable, so children that compare equal keep their source order
+        children.sort_by_key(|child| {
+            let data = &self.forest.nodes[*child];
+            let is_positioned = data.style.position_type == PositionType::Absolute || data.is_portal;
+            (is_positioned, if is_positioned { 0 } else { data.style.order })
+        });
+
+        for child in children {
+            self.collect_paint_order(child, nodes);
+        }
+    }
+
+    /// Returns the [`Diagnostics`](Diagnostic) found by the last call to [`Taffy::compute_layout`]
+    ///
+    /// Diagnostics are listed for the laid out node and its descendants, in depth-first pre-order.
+    /// Nodes whose layout was reused from the cache keep the diagnostics found when it was computed.
+    /// The list is empty if no layout has been computed yet, or if the last root has since been removed.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn last_diagnostics(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        if let Some(id) = self.last_root.and_then(|root| self.find_node(root).ok()) {
+            self.collect_diagnostics(id, &mut diagnostics);
+        }
+        diagnostics
+    }
+
+    /// Returns the time spent laying out each node during the last call to [`Taffy::compute_layout`]
+    ///
+    /// Nodes are listed for the laid out node and its descendants, in depth-first pre-order.
+    /// The time of each node includes that of its descendants, and of every time it was measured by its parent.
+    /// Nodes whose layout was reused from the cache take almost no time.
+    /// The list is empty if no layout has been computed yet, or if the last root has since been removed.
+    ///
+    /// This requires the `profiling` feature, without which no time is measured at all.
+    #[cfg(feature = "profiling")]
+    pub fn last_timings(&self) -> Vec<(Node, std::time::Duration)> {
+        let mut timings = Vec::new();
+        if let Some(id) = self.last_root.and_then(|root| self.find_node(root).ok()) {
+            self.collect_timings(id, &mut timings);
+        }
+        timings
+    }
+
+    /// Appends the layout time of `id` and then, recursively, those of its children to `timings`
+    #[cfg(feature = "profiling")]
+    fn collect_timings(&self, id: NodeId, timings: &mut Vec<(Node, std::time::Duration)>) {
+        timings.push((self.ids_to_nodes[&id], self.forest.nodes[id].layout_time));
+        for child in &self.forest.children[id] {
+            self.collect_timings(*child, timings);
+        }
+    }
+
+    /// Appends the [`Diagnostics`](Diagnostic) of `id` and then, recursively, those of its children to `diagnostics`
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    fn collect_diagnostics(&self, id: NodeId, diagnostics: &mut Vec<Diagnostic>) {
+        let node = self.ids_to_nodes[&id];
+        let unresolved = self.forest.nodes[id].unresolved_percentage_size;
+        if unresolved.width {
+            diagnostics.push(Diagnostic::UnresolvedPercentageWidth(node));
+        }
+        if unresolved.height {
+            diagnostics.push(Diagnostic::UnresolvedPercentageHeight(node));
+        }
+        for child in &self.forest.children[id] {
+            self.collect_diagnostics(*child, diagnostics);
+        }
+    }
+
+    /// Calls `hook` with the [`Layout`] of `id` and then, recursively, with those of its children
+    fn visit_layouts(&self, id: NodeId, hook: &mut impl FnMut(Node, &Layout)) {
+        hook(self.ids_to_nodes[&id], &self.forest.nodes[id].layout);
+        for child in &self.forest.children[id] {
+            self.visit_layouts(*child, hook);
+        }
+    }
+
+    /// Moves each portal node to its place relative to its anchor, once every other node has been laid out
+    fn position_portals(&mut self) {
+        for (portal, anchor) in &self.portal_anchors {
+            let (id, anchor_id) = match (self.find_node(*portal), self.find_node(*anchor)) {
+                (Ok(id), Ok(anchor_id)) => (id, anchor_id),
+                _ => continue,
+            };
+            let parent = match self.forest.parents[id].first() {
+                Some(parent) => *parent,
+                None => continue,
+            };
+
+            let anchor_location = self.absolute_location(anchor_id);
+            let parent_location = self.absolute_location(parent);
+            let anchor_size = self.forest.nodes[anchor_id].layout.size;
+            let layout = self.forest.nodes[id].layout;
+            let position = self.forest.nodes[id].style.position;
+
+            let offset_x = match (
+                position.start.maybe_resolve(Some(anchor_size.width)),
+                position.end.maybe_resolve(Some(anchor_size.width)),
+            ) {
+                (Some(start), _) => start + layout.margin.start,
+                (None, Some(end)) => anchor_size.width - end - layout.margin.end - layout.size.width,
+                (None, None) => layout.margin.start,
+            };
+            let offset_y = match (
+                position.top.maybe_resolve(Some(anchor_size.height)),
+                position.bottom.maybe_resolve(Some(anchor_size.height)),
+            ) {
+                (Some(top), _) => top + layout.margin.top,
+                (None, Some(bottom)) => anchor_size.height - bottom - layout.margin.bottom - layout.size.height,
+                (None, None) => layout.margin.top,
+            };
+
+            let mut location = Point {
+                x: anchor_location.x - parent_location.x + offset_x,
+                y: anchor_location.y - parent_location.y + offset_y,
+            };
+            if self.use_rounding {
+                location = Point { x: round(location.x), y: round(location.y) };
+            }
+            self.forest.nodes[id].layout.location = location;
+        }
+    }
+
+    /// The location of `id` relative to the root of its tree
+    fn absolute_location(&self, id: NodeId) -> Point<f32> {
+        let location = self.forest.nodes[id].layout.location;
+        match self.forest.parents[id].first() {
+            Some(parent) => {
+                let parent_location = self.absolute_location(*parent);
+                Point { x: parent_location.x + location.x, y: parent_location.y + location.y }
+            }
+            None => location,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        style::{Dimension, Display, FlexDirection},
+        geometry::{Point, Rect},
+        style::{Dimension, Display, FlexDirection, FlexWrap, PositionType},
         sys,
     };
 
@@ -472,6 +1207,28 @@ mod tests {
         assert!(taffy.find_node(child1).is_err());
     }
 
+    #[test]
+    fn clear_should_retain_capacity() {
+        fn build(taffy: &mut Taffy) -> Node {
+            let children: Vec<Node> = (0..10).map(|_| taffy.new_leaf(FlexboxLayout::default()).unwrap()).collect();
+            taffy.new_with_children(FlexboxLayout::default(), &children).unwrap()
+        }
+
+        let mut taffy = Taffy::new();
+        let old_root = build(&mut taffy);
+        let capacity = taffy.forest.nodes.capacity();
+
+        taffy.clear();
+        assert_eq!(taffy.forest.len(), 0);
+        assert_eq!(taffy.forest.nodes.capacity(), capacity);
+
+        let new_root = build(&mut taffy);
+        assert_eq!(taffy.forest.len(), 11);
+        assert_eq!(taffy.forest.nodes.capacity(), capacity);
+        assert!(taffy.find_node(old_root).is_err());
+        assert_eq!(taffy.child_count(new_root).unwrap(), 10);
+    }
+
     #[test]
     fn remove_node_should_remove() {
         let mut taffy = Taffy::new();
@@ -515,14 +1272,98 @@ mod tests {
     }
 
     #[test]
-    fn remove_last_node() {
+    fn compact_should_keep_nodes_valid() {
         let mut taffy = Taffy::new();
 
-        let parent = taffy.new_leaf(FlexboxLayout::default()).unwrap();
-        let child = taffy.new_leaf(FlexboxLayout::default()).unwrap();
-        taffy.add_child(parent, child).unwrap();
+        // Children created before their parents are stored out of depth-first order
+        let removed = taffy.new_leaf(FlexboxLayout::default()).unwrap();
+        let child0 = taffy.new_leaf(FlexboxLayout { flex_grow: 1.0, ..Default::default() }).unwrap();
+        let child1 = taffy.new_leaf(FlexboxLayout { flex_grow: 3.0, ..Default::default() }).unwrap();
+        let root = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    size: Size { width: Dimension::Points(100.0), height: Dimension::Points(10.0) },
+                    ..Default::default()
+                },
+                &[child0, child1],
+            )
+            .unwrap();
+        taffy.remove(removed).unwrap();
 
-        taffy.remove(child).unwrap();
+        taffy.compact();
+
+        assert_eq!(taffy.find_node(root).unwrap(), 0);
+        assert_eq!(taffy.find_node(child0).unwrap(), 1);
+        assert_eq!(taffy.find_node(child1).unwrap(), 2);
+        assert!(taffy.find_node(removed).is_err());
+        assert_eq!(taffy.children(root).unwrap().as_slice(), &[child0, child1]);
+        assert_eq!(taffy.style(child1).unwrap().flex_grow, 3.0);
+
+        taffy.compute_layout(root, Size::undefined()).unwrap();
+        assert_eq!(taffy.layout(child0).unwrap().size.width, 25.0);
+        assert_eq!(taffy.layout(child1).unwrap().size.width, 75.0);
+    }
+
+    #[test]
+    fn scroll_size_should_include_relative_offsets() {
+        let mut taffy = Taffy::new();
+
+        let child_style = FlexboxLayout { size: Size::from_points(50.0, 50.0), ..Default::default() };
+        let first = taffy.new_leaf(child_style).unwrap();
+        let offset =
+            taffy.new_leaf(FlexboxLayout { position: Rect::from_points(120.0, 0.0, 0.0, 0.0), ..child_style }).unwrap();
+        let last = taffy.new_leaf(child_style).unwrap();
+        let container = taffy
+            .new_with_children(
+                FlexboxLayout { size: Size::from_points(200.0, 100.0), ..Default::default() },
+                &[first, offset, last],
+            )
+            .unwrap();
+
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+
+        // The offset does not move the siblings...
+        assert_eq!(taffy.layout(offset).unwrap().location.x, 170.0);
+        assert_eq!(taffy.layout(last).unwrap().location.x, 100.0);
+        // ...but the offset child now overflows the container
+        assert_eq!(taffy.scroll_size(container).unwrap(), Size { width: 220.0, height: 100.0 });
+        assert_eq!(taffy.scroll_size(first).unwrap(), Size { width: 50.0, height: 50.0 });
+    }
+
+    #[test]
+    fn content_size_should_contain_overflowing_children() {
+        let mut taffy = Taffy::new();
+
+        let child_style = FlexboxLayout { size: Size::from_points(80.0, 30.0), flex_shrink: 0.0, ..Default::default() };
+        let grandchild =
+            taffy.new_leaf(FlexboxLayout { size: Size::from_points(10.0, 60.0), ..Default::default() }).unwrap();
+        let first = taffy.new_with_children(child_style, &[grandchild]).unwrap();
+        let second = taffy.new_leaf(child_style).unwrap();
+        let container = taffy
+            .new_with_children(
+                FlexboxLayout { size: Size::from_points(100.0, 50.0), ..Default::default() },
+                &[first, second],
+            )
+            .unwrap();
+
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+
+        // The box keeps its declared size, while the content spans both children and the overflow of the first one
+        assert_eq!(taffy.layout(container).unwrap().size, Size { width: 100.0, height: 50.0 });
+        assert_eq!(taffy.content_size(container).unwrap(), Size { width: 160.0, height: 60.0 });
+        assert_eq!(taffy.content_size(first).unwrap(), Size { width: 10.0, height: 60.0 });
+        assert_eq!(taffy.content_size(second).unwrap(), Size::ZERO);
+    }
+
+    #[test]
+    fn remove_last_node() {
+        let mut taffy = Taffy::new();
+
+        let parent = taffy.new_leaf(FlexboxLayout::default()).unwrap();
+        let child = taffy.new_leaf(FlexboxLayout::default()).unwrap();
+        taffy.add_child(parent, child).unwrap();
+
+        taffy.remove(child).unwrap();
         taffy.remove(parent).unwrap();
     }
 
@@ -612,6 +1453,33 @@ mod tests {
         assert_eq!(taffy.child_count(node).unwrap(), 0);
     }
 
+    #[test]
+    fn add_child_at_index() {
+        let mut taffy = Taffy::new();
+        let children: Vec<Node> = (0..4).map(|_| taffy.new_leaf(FlexboxLayout::default()).unwrap()).collect();
+        let node = taffy.new_with_children(FlexboxLayout::default(), &[children[1]]).unwrap();
+        taffy.compute_layout(node, Size::undefined()).unwrap();
+
+        // Front
+        taffy.add_child_at_index(node, 0, children[0]).unwrap();
+        assert!(taffy.dirty(node).unwrap());
+        // End
+        taffy.add_child_at_index(node, 2, children[3]).unwrap();
+        // Middle
+        taffy.add_child_at_index(node, 2, children[2]).unwrap();
+
+        assert_eq!(taffy.children(node).unwrap().as_slice(), children.as_slice());
+        let child_id = taffy.find_node(children[2]).unwrap();
+        assert_eq!(taffy.forest.parents[child_id].as_slice(), &[taffy.find_node(node).unwrap()]);
+
+        let extra = taffy.new_leaf(FlexboxLayout::default()).unwrap();
+        assert!(matches!(
+            taffy.add_child_at_index(node, 5, extra),
+            Err(error::InvalidChild::ChildIndexOutOfBounds { child_index: 5, child_count: 4, .. })
+        ));
+        assert_eq!(taffy.child_count(node).unwrap(), 4);
+    }
+
     #[test]
     fn replace_child_at_index() {
         let mut taffy = Taffy::new();
@@ -657,14 +1525,358 @@ mod tests {
         let child1 = taffy.new_leaf(FlexboxLayout::default()).unwrap();
         let node = taffy.new_with_children(FlexboxLayout::default(), &[child0, child1]).unwrap();
 
-        let mut children: sys::Vec<Node> = sys::Vec::new();
-        children.push(child0);
-        children.push(child1);
+        let children: sys::Vec<Node> = [child0, child1].into_iter().collect();
 
         let children_result = taffy.children(node).unwrap();
         assert_eq!(children_result, children);
 
-        assert!(taffy.children(child0).unwrap().len() == 0);
+        assert!(taffy.children(child0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn children_into_should_reuse_the_buffer() {
+        let mut taffy = Taffy::new();
+        let child0 = taffy.new_leaf(FlexboxLayout::default()).unwrap();
+        let child1 = taffy.new_leaf(FlexboxLayout::default()).unwrap();
+        let child2 = taffy.new_leaf(FlexboxLayout::default()).unwrap();
+        let node0 = taffy.new_with_children(FlexboxLayout::default(), &[child0, child1]).unwrap();
+        let node1 = taffy.new_with_children(FlexboxLayout::default(), &[child2]).unwrap();
+
+        let mut buf = sys::Vec::new();
+        taffy.children_into(node0, &mut buf).unwrap();
+        assert_eq!(buf.as_slice(), &[child0, child1]);
+
+        taffy.children_into(node1, &mut buf).unwrap();
+        assert_eq!(buf.as_slice(), &[child2]);
+
+        taffy.children_into(child0, &mut buf).unwrap();
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    fn parents_should_list_every_parent() {
+        let mut taffy = Taffy::new();
+        let shared = taffy.new_leaf(FlexboxLayout::default()).unwrap();
+        let child = taffy.new_leaf(FlexboxLayout::default()).unwrap();
+        let node0 = taffy.new_with_children(FlexboxLayout::default(), &[shared, child]).unwrap();
+        let node1 = taffy.new_with_children(FlexboxLayout::default(), &[shared]).unwrap();
+
+        assert!(taffy.parents(node0).unwrap().is_empty());
+        assert_eq!(taffy.parent(node0).unwrap(), None);
+
+        assert_eq!(taffy.parents(child).unwrap().as_slice(), &[node0]);
+        assert_eq!(taffy.parent(child).unwrap(), Some(node0));
+
+        assert_eq!(taffy.parents(shared).unwrap().as_slice(), &[node0, node1]);
+        assert_eq!(taffy.parent(shared).unwrap(), Some(node0));
+
+        taffy.remove(node1).unwrap();
+        assert!(matches!(taffy.parents(node1), Err(error::InvalidNode(_))));
+        assert_eq!(taffy.parents(shared).unwrap().as_slice(), &[node0]);
+    }
+
+    #[test]
+    fn compute_layout_at_should_shift_every_node_by_the_origin() {
+        let mut taffy = Taffy::new();
+        let leaf = taffy.new_leaf(FlexboxLayout { size: Size::from_points(10.0, 10.0), ..Default::default() }).unwrap();
+        let absolute = taffy
+            .new_leaf(FlexboxLayout {
+                position_type: PositionType::Absolute,
+                position: Rect { end: Dimension::Points(5.0), ..Default::default() },
+                size: Size::from_points(10.0, 10.0),
+                ..Default::default()
+            })
+            .unwrap();
+        let inner = taffy
+            .new_with_children(
+                FlexboxLayout { padding: Rect::from_points(3.0, 3.0, 3.0, 3.0), ..Default::default() },
+                &[leaf],
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                FlexboxLayout { size: Size::from_points(100.0, 50.0), ..FlexboxLayout::column() },
+                &[inner, absolute],
+            )
+            .unwrap();
+        let nodes = [root, inner, leaf, absolute];
+        let absolute_locations =
+            |taffy: &Taffy| nodes.map(|node| taffy.absolute_location(taffy.find_node(node).unwrap()));
+
+        taffy.compute_layout(root, Size::undefined()).unwrap();
+        let unshifted = absolute_locations(&taffy);
+
+        let size = taffy.compute_layout_at(root, Size::undefined(), Point { x: 40.0, y: 25.0 }).unwrap();
+        assert_eq!(size, Size { width: 100.0, height: 50.0 });
+        let shifted = absolute_locations(&taffy);
+
+        for (shifted, unshifted) in shifted.iter().zip(unshifted) {
+            assert_eq!(*shifted, Point { x: unshifted.x + 40.0, y: unshifted.y + 25.0 });
+        }
+        assert_eq!(taffy.layout(inner).unwrap().location, Point::ZERO);
+
+        // A plain layout puts the root back at the origin
+        taffy.compute_layout(root, Size::undefined()).unwrap();
+        assert_eq!(taffy.layout(root).unwrap().location, Point::ZERO);
+    }
+
+    #[test]
+    fn move_child_should_reparent_the_child() {
+        let mut taffy = Taffy::new();
+        let children = [(); 3].map(|_| taffy.new_leaf(FlexboxLayout::default()).unwrap());
+        let moved = taffy.new_leaf(FlexboxLayout::default()).unwrap();
+        let old_parent = taffy.new_with_children(FlexboxLayout::default(), &[children[0], moved]).unwrap();
+        let new_parent = taffy.new_with_children(FlexboxLayout::default(), &[children[1], children[2]]).unwrap();
+        taffy.compute_layout(old_parent, Size::undefined()).unwrap();
+        taffy.compute_layout(new_parent, Size::undefined()).unwrap();
+
+        taffy.move_child(old_parent, moved, new_parent, Some(1)).unwrap();
+
+        assert_eq!(taffy.children(old_parent).unwrap().as_slice(), &[children[0]]);
+        assert_eq!(taffy.children(new_parent).unwrap().as_slice(), &[children[1], moved, children[2]]);
+        assert_eq!(taffy.parents(moved).unwrap().as_slice(), &[new_parent]);
+        assert!(taffy.dirty(old_parent).unwrap());
+        assert!(taffy.dirty(new_parent).unwrap());
+
+        // Without an index the child is appended, and within a parent the index ignores the child itself
+        taffy.move_child(new_parent, moved, old_parent, None).unwrap();
+        assert_eq!(taffy.children(old_parent).unwrap().as_slice(), &[children[0], moved]);
+        taffy.move_child(old_parent, moved, old_parent, Some(0)).unwrap();
+        assert_eq!(taffy.children(old_parent).unwrap().as_slice(), &[moved, children[0]]);
+        assert_eq!(taffy.parents(moved).unwrap().as_slice(), &[old_parent]);
+    }
+
+    #[test]
+    fn move_child_should_leave_the_tree_untouched_on_error() {
+        let mut taffy = Taffy::new();
+        let child = taffy.new_leaf(FlexboxLayout::default()).unwrap();
+        let other = taffy.new_leaf(FlexboxLayout::default()).unwrap();
+        let old_parent = taffy.new_with_children(FlexboxLayout::default(), &[child]).unwrap();
+        let new_parent = taffy.new_with_children(FlexboxLayout::default(), &[other]).unwrap();
+
+        assert!(matches!(
+            taffy.move_child(old_parent, child, new_parent, Some(2)),
+            Err(error::InvalidChild::ChildIndexOutOfBounds { child_index: 2, child_count: 1, .. })
+        ));
+        assert!(matches!(
+            taffy.move_child(new_parent, child, old_parent, None),
+            Err(error::InvalidChild::NotAChild { parent, child: not_a_child }) if parent == new_parent && not_a_child == child
+        ));
+
+        assert_eq!(taffy.children(old_parent).unwrap().as_slice(), &[child]);
+        assert_eq!(taffy.children(new_parent).unwrap().as_slice(), &[other]);
+        assert_eq!(taffy.parents(child).unwrap().as_slice(), &[old_parent]);
+    }
+
+    #[test]
+    fn all_nodes_should_include_every_node() {
+        let mut taffy = Taffy::new();
+        assert_eq!(taffy.all_nodes().count(), 0);
+
+        let children = [(); 3].map(|_| taffy.new_leaf(FlexboxLayout::default()).unwrap());
+        let root = taffy.new_with_children(FlexboxLayout::default(), &children[..2]).unwrap();
+        let orphan = taffy.new_leaf(FlexboxLayout::default()).unwrap();
+        taffy.remove(children[2]).unwrap();
+
+        let mut all_nodes: sys::Vec<Node> = taffy.all_nodes().collect();
+        assert_eq!(all_nodes.len(), taffy.node_count());
+        all_nodes.sort_by_key(|node| node.index());
+        assert_eq!(all_nodes.as_slice(), &[children[0], children[1], root, orphan]);
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn assert_idempotent_should_accept_a_stable_layout() {
+        let mut taffy = Taffy::new();
+        taffy.enable_rounding();
+
+        let measured = taffy
+            .new_leaf_with_measure(
+                FlexboxLayout { flex_grow: 1.0, ..Default::default() },
+                MeasureFunc::Raw(|known| Size { width: known.width.unwrap_or(33.3), height: 12.7 }),
+            )
+            .unwrap();
+        let ratio = taffy
+            .new_leaf(FlexboxLayout {
+                size: Size { width: Dimension::Percent(0.3), height: Dimension::Auto },
+                aspect_ratio: Some(1.5),
+                ..Default::default()
+            })
+            .unwrap();
+        let wrapped = [(); 4].map(|_| {
+            taffy
+                .new_leaf(FlexboxLayout {
+                    flex_shrink: 0.0,
+                    size: Size::from_points(45.5, 20.0),
+                    margin: Rect::from_points(1.5, 1.5, 0.0, 0.0),
+                    ..Default::default()
+                })
+                .unwrap()
+        });
+        let row = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    padding: Rect::from_points(3.0, 3.0, 3.0, 3.0),
+                    align_content: crate::style::AlignContent::SpaceAround,
+                    ..FlexboxLayout::row().wrap()
+                },
+                &wrapped,
+            )
+            .unwrap();
+        let absolute = taffy
+            .new_leaf(FlexboxLayout {
+                position_type: PositionType::Absolute,
+                position: Rect { end: Dimension::Percent(0.1), bottom: Dimension::Points(4.0), ..Default::default() },
+                size: Size::from_points(10.0, 10.0),
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    size: Size { width: Dimension::Points(157.0), height: Dimension::Auto },
+                    ..FlexboxLayout::column()
+                },
+                &[measured, ratio, row, absolute],
+            )
+            .unwrap();
+
+        taffy.assert_idempotent(root, Size { width: Some(200.0), height: None }).unwrap();
+    }
+
+    #[test]
+    fn compute_layout_should_skip_an_unchanged_tree() {
+        let mut taffy = Taffy::new();
+        let child = taffy.new_leaf(FlexboxLayout { flex_grow: 1.0, ..Default::default() }).unwrap();
+        let root = taffy
+            .new_with_children(FlexboxLayout { size: Size::from_percent(1.0, 1.0), ..Default::default() }, &[child])
+            .unwrap();
+        let available_space = Size { width: Some(100.0), height: Some(50.0) };
+        taffy.compute_layout(root, available_space).unwrap();
+        assert_eq!(taffy.forest.compute_count, 1);
+
+        taffy.compute_layout(root, available_space).unwrap();
+        assert_eq!(taffy.forest.compute_count, 1);
+
+        taffy.mark_dirty(child).unwrap();
+        taffy.compute_layout(root, available_space).unwrap();
+        assert_eq!(taffy.forest.compute_count, 2);
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 100.0, height: 50.0 });
+
+        taffy.compute_layout(root, Size { width: Some(80.0), height: Some(50.0) }).unwrap();
+        assert_eq!(taffy.forest.compute_count, 3);
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 80.0, height: 50.0 });
+
+        // Measuring the root doesn't lay it out, so it must not count as an up to date layout
+        taffy.mark_dirty(root).unwrap();
+        taffy.intrinsic_sizes(root).unwrap();
+        taffy.compute_layout(root, Size { width: Some(80.0), height: Some(50.0) }).unwrap();
+        assert_eq!(taffy.forest.compute_count, 4);
+    }
+
+    #[test]
+    fn remeasure_should_call_the_measure_func_and_be_reused_by_the_next_layout() {
+        use core::sync::atomic::{AtomicU32, Ordering};
+
+        static NUM_MEASURES: AtomicU32 = AtomicU32::new(0);
+
+        let mut taffy = Taffy::new();
+        let leaf = taffy
+            .new_leaf_with_measure(
+                FlexboxLayout::default(),
+                MeasureFunc::Raw(|known| {
+                    NUM_MEASURES.fetch_add(1, Ordering::Relaxed);
+                    Size { width: known.width.unwrap_or(40.0), height: 10.0 }
+                }),
+            )
+            .unwrap();
+        let root = taffy.new_with_children(FlexboxLayout::default(), &[leaf]).unwrap();
+
+        assert_eq!(taffy.remeasure(leaf, Size::NONE).unwrap(), Size { width: 40.0, height: 10.0 });
+        assert_eq!(taffy.remeasure(leaf, Size::NONE).unwrap(), Size { width: 40.0, height: 10.0 });
+        assert_eq!(NUM_MEASURES.load(Ordering::Relaxed), 2);
+        assert_eq!(
+            taffy.remeasure(leaf, Size { width: Some(25.0), height: None }).unwrap(),
+            Size { width: 25.0, height: 10.0 }
+        );
+        assert_eq!(NUM_MEASURES.load(Ordering::Relaxed), 3);
+
+        taffy.compute_layout(root, Size::NONE).unwrap();
+        assert_eq!(taffy.layout(leaf).unwrap().size, Size { width: 40.0, height: 10.0 });
+        assert_eq!(NUM_MEASURES.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn root_should_follow_its_node_when_it_is_moved_by_a_removal() {
+        let mut taffy = Taffy::new();
+        assert_eq!(taffy.root(), None);
+        assert_eq!(taffy.compute_layout_root(Size::NONE).unwrap(), None);
+
+        let removed = taffy.new_leaf(FlexboxLayout::default()).unwrap();
+        let child =
+            taffy.new_leaf(FlexboxLayout { size: Size::from_points(30.0, 20.0), ..Default::default() }).unwrap();
+        let root = taffy.new_with_children(FlexboxLayout::default(), &[child]).unwrap();
+        taffy.set_root(root).unwrap();
+
+        // The root is stored last, so it takes the place of the removed node
+        taffy.remove(removed).unwrap();
+        assert_eq!(taffy.find_node(root).unwrap(), 0);
+        assert_eq!(taffy.root(), Some(root));
+        assert_eq!(taffy.compute_layout_root(Size::NONE).unwrap(), Some(Size { width: 30.0, height: 20.0 }));
+
+        taffy.remove(root).unwrap();
+        assert_eq!(taffy.root(), None);
+        assert_eq!(taffy.compute_layout_root(Size::NONE).unwrap(), None);
+        assert!(taffy.set_root(root).is_err());
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn snapshot_should_match_the_live_layouts() {
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+        let mut taffy = Taffy::new();
+        let leaves = [(); 2].map(|_| {
+            taffy.new_leaf(FlexboxLayout { size: Size::from_points(20.0, 10.0), ..Default::default() }).unwrap()
+        });
+        let row = taffy.new_with_children(FlexboxLayout::row(), &leaves).unwrap();
+        let root = taffy.new_with_children(FlexboxLayout::column(), &[row]).unwrap();
+        taffy.compute_layout(root, Size::NONE).unwrap();
+
+        let snapshot = taffy.snapshot();
+        assert_send_sync(&snapshot);
+        assert_eq!(snapshot.node_count(), taffy.node_count());
+        for node in taffy.all_nodes() {
+            assert_eq!(snapshot.layout(node).unwrap(), taffy.layout(node).unwrap());
+            assert_eq!(snapshot.children(node).unwrap(), taffy.children(node).unwrap().as_slice());
+        }
+
+        // The snapshot is unaffected by later changes to the tree
+        taffy.remove(leaves[0]).unwrap();
+        taffy.compute_layout(root, Size::NONE).unwrap();
+        assert_eq!(snapshot.children(row).unwrap(), &leaves);
+        assert_eq!(snapshot.layout(leaves[1]).unwrap().location.x, 20.0);
+        assert!(snapshot.layout(root).is_ok());
+    }
+
+    #[test]
+    fn node_display() {
+        use std::string::ToString;
+
+        let mut taffy = Taffy::new();
+        let node0 = taffy.new_leaf(FlexboxLayout::default()).unwrap();
+        let node1 = taffy.new_leaf(FlexboxLayout::default()).unwrap();
+
+        assert_eq!(node0.to_string(), "Node#0");
+        assert_eq!(node1.to_string(), "Node#1");
+
+        // The errors only implement `Display` with the std feature
+        #[cfg(feature = "std")]
+        {
+            taffy.remove(node1).unwrap();
+            assert_eq!(taffy.layout(node1).unwrap_err().to_string(), "Node#1 is not in the Taffy instance");
+        }
     }
     #[test]
     fn test_set_style() {
@@ -676,6 +1888,63 @@ mod tests {
         taffy.set_style(node, FlexboxLayout { display: Display::None, ..FlexboxLayout::default() }).unwrap();
         assert_eq!(taffy.style(node).unwrap().display, Display::None);
     }
+
+    #[test]
+    fn test_update_style() {
+        let mut taffy = Taffy::new();
+        let child = taffy.new_leaf(FlexboxLayout::default()).unwrap();
+        let node = taffy.new_with_children(FlexboxLayout::default(), &[child]).unwrap();
+        taffy.compute_layout(node, Size::undefined()).unwrap();
+
+        taffy.update_style(child, |style| style.display = Display::Flex).unwrap();
+        assert!(!taffy.dirty(child).unwrap());
+        assert!(!taffy.dirty(node).unwrap());
+
+        taffy.update_style(child, |style| style.display = Display::None).unwrap();
+        assert_eq!(taffy.style(child).unwrap().display, Display::None);
+        assert!(taffy.dirty(child).unwrap());
+        assert!(taffy.dirty(node).unwrap());
+    }
+
+    #[test]
+    fn set_style_should_only_invalidate_affected_caches() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf_with_measure(
+                FlexboxLayout::default(),
+                MeasureFunc::Raw(|known| Size {
+                    width: known.width.unwrap_or(50.0),
+                    height: known.height.unwrap_or(50.0),
+                }),
+            )
+            .unwrap();
+        let node = taffy
+            .new_with_children(FlexboxLayout { flex_direction: FlexDirection::Column, ..Default::default() }, &[child])
+            .unwrap();
+        taffy.compute_layout(node, Size::undefined()).unwrap();
+
+        let child_id = taffy.find_node(child).unwrap();
+        let node_id = taffy.find_node(node).unwrap();
+        // The node size each slot was computed for, and the resulting size
+        let cached_sizes = |taffy: &Taffy, id: NodeId| {
+            taffy.forest.nodes[id].layout_cache.map(|cache| cache.map(|cache| (cache.node_size, cache.size)))
+        };
+        let child_cache = cached_sizes(&taffy, child_id);
+        assert!(child_cache.iter().any(Option::is_some));
+
+        // Changing the height is resolved by the parent, so every slot of the child's cache remains as it was
+        taffy.update_style(child, |style| style.size.height = Dimension::Points(100.0)).unwrap();
+        assert!(taffy.dirty(child).unwrap());
+        assert_eq!(cached_sizes(&taffy, child_id), child_cache);
+        assert_eq!(cached_sizes(&taffy, node_id), [None; crate::forest::CACHE_SIZE]);
+
+        taffy.compute_layout(node, Size::undefined()).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 50.0, height: 100.0 });
+
+        // Padding changes the inside of the child, so every slot of its cache is cleared
+        taffy.update_style(child, |style| style.padding = Rect::from_points(1.0, 1.0, 1.0, 1.0)).unwrap();
+        assert_eq!(cached_sizes(&taffy, child_id), [None; crate::forest::CACHE_SIZE]);
+    }
     #[test]
     fn test_style() {
         let mut taffy = Taffy::new();
@@ -708,20 +1977,20 @@ mod tests {
 
         taffy.compute_layout(node, Size::undefined()).unwrap();
 
-        assert_eq!(taffy.dirty(child0).unwrap(), false);
-        assert_eq!(taffy.dirty(child1).unwrap(), false);
-        assert_eq!(taffy.dirty(node).unwrap(), false);
+        assert!(!taffy.dirty(child0).unwrap());
+        assert!(!taffy.dirty(child1).unwrap());
+        assert!(!taffy.dirty(node).unwrap());
 
         taffy.mark_dirty(node).unwrap();
-        assert_eq!(taffy.dirty(child0).unwrap(), false);
-        assert_eq!(taffy.dirty(child1).unwrap(), false);
-        assert_eq!(taffy.dirty(node).unwrap(), true);
+        assert!(!taffy.dirty(child0).unwrap());
+        assert!(!taffy.dirty(child1).unwrap());
+        assert!(taffy.dirty(node).unwrap());
 
         taffy.compute_layout(node, Size::undefined()).unwrap();
         taffy.mark_dirty(child0).unwrap();
-        assert_eq!(taffy.dirty(child0).unwrap(), true);
-        assert_eq!(taffy.dirty(child1).unwrap(), false);
-        assert_eq!(taffy.dirty(node).unwrap(), true);
+        assert!(taffy.dirty(child0).unwrap());
+        assert!(!taffy.dirty(child1).unwrap());
+        assert!(taffy.dirty(node).unwrap());
     }
 
     #[test]
@@ -737,6 +2006,452 @@ mod tests {
         assert!(layout_result.is_ok());
     }
 
+    #[test]
+    fn compute_layout_with_hook_should_visit_each_node_once_in_order() {
+        let mut taffy = Taffy::new();
+        let grandchild = taffy.new_leaf(FlexboxLayout::default()).unwrap();
+        let child0 = taffy.new_with_children(FlexboxLayout::default(), &[grandchild]).unwrap();
+        let child1 = taffy.new_leaf(FlexboxLayout::default()).unwrap();
+        let node = taffy.new_with_children(FlexboxLayout::default(), &[child0, child1]).unwrap();
+
+        let mut visited = Vec::new();
+        taffy.compute_layout_with_hook(node, Size::undefined(), |node, layout| visited.push((node, *layout))).unwrap();
+
+        let nodes: Vec<Node> = visited.iter().map(|(node, _)| *node).collect();
+        assert_eq!(nodes.as_slice(), [node, child0, grandchild, child1]);
+        for (node, layout) in visited {
+            assert_eq!(layout.size, taffy.layout(node).unwrap().size);
+            assert_eq!(layout.location, taffy.layout(node).unwrap().location);
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn compute_layout_into_should_match_layout() {
+        let mut taffy = Taffy::new();
+        let unrelated = taffy.new_leaf(FlexboxLayout::default()).unwrap();
+        let grandchild =
+            taffy.new_leaf(FlexboxLayout { size: Size::from_points(10.0, 20.0), ..Default::default() }).unwrap();
+        let child0 = taffy.new_with_children(FlexboxLayout::default(), &[grandchild]).unwrap();
+        let child1 =
+            taffy.new_leaf(FlexboxLayout { size: Size::from_points(30.0, 20.0), ..Default::default() }).unwrap();
+        let node = taffy.new_with_children(FlexboxLayout::default(), &[child0, child1]).unwrap();
+        taffy.remove(unrelated).unwrap();
+
+        let mut out = vec![Layout::new(); 8];
+        let root_size = taffy.compute_layout_into(node, Size::undefined(), &mut out).unwrap();
+
+        assert_eq!(root_size, Size { width: 40.0, height: 20.0 });
+        assert_eq!(out.len(), 4);
+        for (index, node) in [node, child0, grandchild, child1].into_iter().enumerate() {
+            let layout = taffy.layout(node).unwrap();
+            assert_eq!(out[index].size, layout.size);
+            assert_eq!(out[index].location, layout.location);
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn flex_lines_should_match_item_positions() {
+        let mut taffy = Taffy::new();
+        let item_style = FlexboxLayout { size: Size::from_points(40.0, 10.0), ..Default::default() };
+        let items: Vec<Node> = (0..5).map(|_| taffy.new_leaf(item_style).unwrap()).collect();
+        let absolute =
+            taffy.new_leaf(FlexboxLayout { position_type: PositionType::Absolute, ..Default::default() }).unwrap();
+        let mut children = items.clone();
+        children.insert(2, absolute);
+        let container = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    flex_wrap: FlexWrap::Wrap,
+                    size: Size::from_points(100.0, 100.0),
+                    ..Default::default()
+                },
+                &children,
+            )
+            .unwrap();
+
+        taffy.compute_layout(container, Size::undefined()).unwrap();
+
+        let lines = taffy.flex_lines(container).unwrap();
+        assert_eq!(lines, [vec![items[0], items[1]], vec![items[2], items[3]], vec![items[4]]]);
+        for line in &lines {
+            let y = taffy.layout(line[0]).unwrap().location.y;
+            assert!(line.iter().all(|item| taffy.layout(*item).unwrap().location.y == y));
+        }
+        assert!(taffy.flex_lines(items[0]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn frozen_subtree_should_not_be_recomputed() {
+        let mut taffy = Taffy::new();
+        let grandchild =
+            taffy.new_leaf(FlexboxLayout { size: Size::from_points(10.0, 10.0), ..Default::default() }).unwrap();
+        let child = taffy.new_with_children(FlexboxLayout::default(), &[grandchild]).unwrap();
+        let sibling =
+            taffy.new_leaf(FlexboxLayout { size: Size::from_points(5.0, 5.0), ..Default::default() }).unwrap();
+        let node = taffy.new_with_children(FlexboxLayout::default(), &[sibling, child]).unwrap();
+        taffy.compute_layout(node, Size::undefined()).unwrap();
+
+        taffy.freeze_subtree(child).unwrap();
+        taffy
+            .set_style(grandchild, FlexboxLayout { size: Size::from_points(30.0, 30.0), ..Default::default() })
+            .unwrap();
+        taffy.set_style(sibling, FlexboxLayout { size: Size::from_points(15.0, 5.0), ..Default::default() }).unwrap();
+        taffy.compute_layout(node, Size::undefined()).unwrap();
+
+        // The frozen child keeps its size, but is still moved by its parent
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 10.0, height: 10.0 });
+        assert_eq!(taffy.layout(child).unwrap().location.x, 15.0);
+        assert_eq!(taffy.layout(grandchild).unwrap().size, Size { width: 10.0, height: 10.0 });
+        assert!(taffy.dirty(grandchild).unwrap());
+
+        taffy.unfreeze_subtree(child).unwrap();
+        taffy.compute_layout(node, Size::undefined()).unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 30.0, height: 30.0 });
+        assert_eq!(taffy.layout(grandchild).unwrap().size, Size { width: 30.0, height: 30.0 });
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn from_description_should_build_the_tree() {
+        let description: TreeDescription = serde_json::from_str(
+            r#"{
+                "style": { "size": { "width": { "Points": 100.0 }, "height": { "Points": 40.0 } } },
+                "children": [
+                    { "style": { "flex_grow": 1.0 } },
+                    { "style": { "size": { "width": { "Points": 30.0 } } }, "children": [{}] }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let (mut taffy, root) = Taffy::from_description(&description).unwrap();
+        taffy.compute_layout(root, Size::undefined()).unwrap();
+
+        let children = taffy.children(root).unwrap();
+        assert_eq!(children.len(), 2);
+        assert_eq!(taffy.child_count(children[1]).unwrap(), 1);
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 100.0, height: 40.0 });
+        assert_eq!(taffy.layout(children[0]).unwrap().size.width, 70.0);
+        assert_eq!(taffy.layout(children[1]).unwrap().location.x, 70.0);
+    }
+
+    #[test]
+    fn intrinsic_sizes_should_be_cached_until_dirty() {
+        let mut taffy = Taffy::new();
+        let wide = taffy.new_leaf(FlexboxLayout { size: Size::from_points(30.0, 10.0), ..Default::default() }).unwrap();
+        let narrow =
+            taffy.new_leaf(FlexboxLayout { size: Size::from_points(20.0, 10.0), ..Default::default() }).unwrap();
+        let node = taffy
+            .new_with_children(FlexboxLayout { flex_wrap: FlexWrap::Wrap, ..Default::default() }, &[wide, narrow])
+            .unwrap();
+        let id = taffy.find_node(node).unwrap();
+
+        // Without any space, each item wraps onto its own line
+        let (min_content_size, max_content_size) = taffy.intrinsic_sizes(node).unwrap();
+        assert_eq!(min_content_size, Size { width: 30.0, height: 20.0 });
+        assert_eq!(max_content_size, Size { width: 50.0, height: 10.0 });
+        assert_eq!(taffy.forest.nodes[id].max_content_size, Some(max_content_size));
+
+        taffy.set_style(narrow, FlexboxLayout { size: Size::from_points(40.0, 10.0), ..Default::default() }).unwrap();
+        assert_eq!(taffy.forest.nodes[id].min_content_size, None);
+        assert_eq!(taffy.forest.nodes[id].max_content_size, None);
+
+        let (min_content_size, max_content_size) = taffy.intrinsic_sizes(node).unwrap();
+        assert_eq!(min_content_size, Size { width: 40.0, height: 20.0 });
+        assert_eq!(max_content_size, Size { width: 70.0, height: 10.0 });
+        assert_eq!(taffy.forest.nodes[id].min_content_size, Some(min_content_size));
+    }
+
+    #[test]
+    fn intrinsic_sizes_of_a_measured_leaf_are_equal() {
+        // Like wrapping text, this would be narrower if the measure function was told that there is no space
+        let leaf = MeasureFunc::Raw(|known_dimensions| Size {
+            width: known_dimensions.width.unwrap_or(100.0),
+            height: known_dimensions.width.map_or(10.0, |width| 1000.0 / width),
+        });
+        let mut taffy = Taffy::new();
+        let node = taffy.new_leaf_with_measure(FlexboxLayout::default(), leaf).unwrap();
+
+        let (min_content_size, max_content_size) = taffy.intrinsic_sizes(node).unwrap();
+        assert_eq!(min_content_size, Size { width: 100.0, height: 10.0 });
+        assert_eq!(max_content_size, min_content_size);
+    }
+
+    #[test]
+    fn compute_layout_should_return_root_size() {
+        let mut taffy = Taffy::new();
+        let child0 =
+            taffy.new_leaf(FlexboxLayout { size: Size::from_points(10.0, 20.0), ..Default::default() }).unwrap();
+        let child1 =
+            taffy.new_leaf(FlexboxLayout { size: Size::from_points(30.0, 20.0), ..Default::default() }).unwrap();
+        let node = taffy.new_with_children(FlexboxLayout::default(), &[child0, child1]).unwrap();
+
+        let size = taffy.compute_layout(node, Size::undefined()).unwrap();
+        assert_eq!(size, Size { width: 40.0, height: 20.0 });
+        assert_eq!(size, taffy.layout(node).unwrap().size);
+    }
+
+    #[test]
+    fn compute_layout_should_only_round_when_enabled() {
+        let mut taffy = Taffy::new();
+        let child = taffy.new_leaf(FlexboxLayout { flex_grow: 1.0, ..Default::default() }).unwrap();
+        let node = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    size: Size { width: Dimension::Points(10.5), height: Dimension::Points(10.5) },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+
+        taffy.compute_layout(node, Size::undefined()).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 10.5, height: 10.5 });
+
+        taffy.enable_rounding();
+        taffy.compute_layout(node, Size::undefined()).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 11.0, height: 11.0 });
+
+        taffy.disable_rounding();
+        taffy.compute_layout(node, Size::undefined()).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 10.5, height: 10.5 });
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn last_diagnostics_should_list_unresolvable_percentages() {
+        let mut taffy = Taffy::new();
+        let leaf = taffy
+            .new_leaf(FlexboxLayout {
+                size: Size { width: Dimension::Percent(0.5), height: Dimension::Percent(0.5) },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    size: Size { width: Dimension::Points(100.0), height: Dimension::Auto },
+                    ..Default::default()
+                },
+                &[leaf],
+            )
+            .unwrap();
+        assert!(taffy.last_diagnostics().is_empty());
+
+        taffy.compute_layout(root, Size::NONE).unwrap();
+        assert_eq!(taffy.last_diagnostics(), vec![Diagnostic::UnresolvedPercentageHeight(leaf)]);
+
+        // The diagnostics of cached nodes are kept
+        taffy.compute_layout(root, Size::NONE).unwrap();
+        assert_eq!(taffy.last_diagnostics(), vec![Diagnostic::UnresolvedPercentageHeight(leaf)]);
+
+        taffy.update_style(root, |style| style.size.height = Dimension::Points(100.0)).unwrap();
+        taffy.compute_layout(root, Size::NONE).unwrap();
+        assert!(taffy.last_diagnostics().is_empty());
+    }
+
+    #[test]
+    fn layout_subtree_should_force_the_size_of_its_root() {
+        let mut taffy = Taffy::new();
+        let grandchild = taffy.new_leaf(FlexboxLayout { flex_grow: 1.0, ..Default::default() }).unwrap();
+        let child = taffy
+            .new_with_children(
+                FlexboxLayout {
+                    size: Size { width: Dimension::Points(10.0), height: Dimension::Points(10.0) },
+                    padding: Rect::from_points(5.0, 5.0, 5.0, 5.0),
+                    ..Default::default()
+                },
+                &[grandchild],
+            )
+            .unwrap();
+        let root = taffy.new_with_children(FlexboxLayout::default(), &[child]).unwrap();
+
+        taffy.layout_subtree(child, Size { width: 200.0, height: 100.0 }).unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 200.0, height: 100.0 });
+        assert_eq!(taffy.layout(grandchild).unwrap().size, Size { width: 190.0, height: 90.0 });
+        assert_eq!(taffy.layout(grandchild).unwrap().location, Point { x: 5.0, y: 5.0 });
+        // The ancestors are not laid out
+        assert_eq!(taffy.layout(root).unwrap().size, Size::ZERO);
+    }
+
+    #[test]
+    fn portal_should_be_positioned_relative_to_its_anchor() {
+        let mut taffy = Taffy::new();
+        let column = |children: &[Node], taffy: &mut Taffy| {
+            let style = FlexboxLayout {
+                flex_direction: FlexDirection::Column,
+                size: Size { width: Dimension::Points(100.0), height: Dimension::Points(200.0) },
+                padding: Rect::from_points(30.0, 0.0, 30.0, 0.0),
+                ..Default::default()
+            };
+            taffy.new_with_children(style, children).unwrap()
+        };
+        let leaf_style = FlexboxLayout {
+            size: Size { width: Dimension::Points(20.0), height: Dimension::Points(20.0) },
+            ..Default::default()
+        };
+
+        let anchor = taffy.new_leaf(leaf_style).unwrap();
+        let portal = taffy
+            .new_leaf(FlexboxLayout {
+                position: Rect { top: Dimension::Percent(1.0), ..Default::default() },
+                ..leaf_style
+            })
+            .unwrap();
+        let sibling = taffy.new_leaf(leaf_style).unwrap();
+        let left = column(&[anchor], &mut taffy);
+        let right = column(&[portal, sibling], &mut taffy);
+        let root = taffy.new_with_children(FlexboxLayout::default(), &[left, right]).unwrap();
+
+        taffy.set_portal_anchor(portal, anchor).unwrap();
+        taffy.compute_layout(root, Size::NONE).unwrap();
+
+        // The anchor is at (30, 30) and `right` at (100, 0), so the portal sits just below the anchor
+        assert_eq!(taffy.layout(portal).unwrap().location, Point { x: -70.0, y: 50.0 });
+        // The portal takes no space in its parent
+        assert_eq!(taffy.layout(sibling).unwrap().location, Point { x: 30.0, y: 30.0 });
+
+        // Moving the anchor moves the portal, even though its parent hasn't changed
+        taffy.update_style(left, |style| style.padding.top = Dimension::Points(60.0)).unwrap();
+        taffy.compute_layout(root, Size::NONE).unwrap();
+        assert_eq!(taffy.layout(portal).unwrap().location, Point { x: -70.0, y: 80.0 });
+
+        assert_eq!(taffy.clear_portal_anchor(portal).unwrap(), Some(anchor));
+        taffy.compute_layout(root, Size::NONE).unwrap();
+        assert_eq!(taffy.layout(portal).unwrap().location.x, 30.0);
+        assert_eq!(taffy.layout(sibling).unwrap().location, Point { x: 30.0, y: 50.0 });
+    }
+
+    #[test]
+    fn portal_should_follow_an_anchor_in_another_tree() {
+        let mut taffy = Taffy::new();
+        let leaf_style = FlexboxLayout { size: Size::from_points(20.0, 20.0), ..Default::default() };
+
+        let anchor = taffy.new_leaf(leaf_style).unwrap();
+        let anchor_root = taffy
+            .new_with_children(
+                FlexboxLayout { padding: Rect::from_points(10.0, 0.0, 40.0, 0.0), ..Default::default() },
+                &[anchor],
+            )
+            .unwrap();
+        let portal = taffy
+            .new_leaf(FlexboxLayout {
+                position: Rect { top: Dimension::Percent(1.0), ..Default::default() },
+                ..leaf_style
+            })
+            .unwrap();
+        let portal_root = taffy.new_with_children(FlexboxLayout::default(), &[portal]).unwrap();
+
+        taffy.set_portal_anchor(portal, anchor).unwrap();
+        taffy.compute_layout(anchor_root, Size::NONE).unwrap();
+        taffy.compute_layout(portal_root, Size::NONE).unwrap();
+        assert_eq!(taffy.layout(portal).unwrap().location, Point { x: 10.0, y: 60.0 });
+
+        // The tree of the anchor is laid out again afterwards, and moves the portal along with the anchor
+        taffy.update_style(anchor_root, |style| style.padding.top = Dimension::Points(70.0)).unwrap();
+        taffy.compute_layout(anchor_root, Size::NONE).unwrap();
+        assert_eq!(taffy.layout(portal).unwrap().location, Point { x: 10.0, y: 90.0 });
+
+        // Laying out the unchanged tree of the portal twice in a row takes the fast path the second time
+        taffy.compute_layout(portal_root, Size::NONE).unwrap();
+        let compute_count = taffy.forest.compute_count;
+        taffy.compute_layout(portal_root, Size::NONE).unwrap();
+        assert_eq!(taffy.forest.compute_count, compute_count);
+        assert_eq!(taffy.layout(portal).unwrap().location, Point { x: 10.0, y: 90.0 });
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn paint_order_should_put_positioned_children_after_in_flow_ones() {
+        let mut taffy = Taffy::new();
+        let absolute =
+            taffy.new_leaf(FlexboxLayout { position_type: PositionType::Absolute, ..Default::default() }).unwrap();
+        let grandchild = taffy.new_leaf(FlexboxLayout::default()).unwrap();
+        let first = taffy.new_with_children(FlexboxLayout::default(), &[grandchild]).unwrap();
+        let reordered = taffy.new_leaf(FlexboxLayout { order: -1, ..Default::default() }).unwrap();
+        let hidden = taffy.new_leaf(FlexboxLayout { display: Display::None, ..Default::default() }).unwrap();
+        let last = taffy.new_leaf(FlexboxLayout::default()).unwrap();
+        let root =
+            taffy.new_with_children(FlexboxLayout::default(), &[absolute, first, reordered, hidden, last]).unwrap();
+
+        assert_eq!(taffy.paint_order(root).unwrap(), vec![root, reordered, first, grandchild, last, absolute]);
+        assert_eq!(taffy.paint_order(first).unwrap(), vec![first, grandchild]);
+    }
+
+    #[test]
+    fn toggling_display_should_not_recompute_unrelated_siblings() {
+        use core::sync::atomic::{AtomicU32, Ordering};
+
+        static NUM_MEASURES: AtomicU32 = AtomicU32::new(0);
+
+        let mut taffy = Taffy::new();
+        let fixed_size = FlexboxLayout { size: Size::from_points(50.0, 50.0), ..Default::default() };
+        let toggled = taffy.new_leaf(fixed_size).unwrap();
+        let sibling = taffy
+            .new_leaf_with_measure(
+                FlexboxLayout::default(),
+                MeasureFunc::Raw(|_| {
+                    NUM_MEASURES.fetch_add(1, Ordering::Relaxed);
+                    Size { width: 10.0, height: 10.0 }
+                }),
+            )
+            .unwrap();
+        let container = taffy
+            .new_with_children(
+                FlexboxLayout { size: Size::from_points(300.0, 100.0), ..Default::default() },
+                &[toggled, sibling],
+            )
+            .unwrap();
+        taffy.compute_layout(container, Size::NONE).unwrap();
+
+        // The sibling is measured again only if its cached results are discarded or don't match
+        let num_measures = NUM_MEASURES.load(Ordering::Relaxed);
+
+        taffy.update_style(toggled, |style| style.display = Display::None).unwrap();
+        taffy.compute_layout(container, Size::NONE).unwrap();
+        assert_eq!(taffy.layout(toggled).unwrap().size, Size::ZERO);
+        assert_eq!(taffy.layout(sibling).unwrap().location.x, 0.0);
+
+        taffy.update_style(toggled, |style| style.display = Display::Flex).unwrap();
+        taffy.compute_layout(container, Size::NONE).unwrap();
+        assert_eq!(taffy.layout(toggled).unwrap().size, Size { width: 50.0, height: 50.0 });
+        assert_eq!(taffy.layout(sibling).unwrap().location.x, 50.0);
+
+        assert_eq!(NUM_MEASURES.load(Ordering::Relaxed), num_measures);
+    }
+
+    #[cfg(feature = "profiling")]
+    #[test]
+    fn last_timings_should_include_every_laid_out_node() {
+        use std::time::Duration;
+
+        let mut taffy = Taffy::new();
+        let slow = taffy
+            .new_leaf_with_measure(
+                FlexboxLayout::default(),
+                MeasureFunc::Raw(|_| {
+                    std::thread::sleep(Duration::from_millis(1));
+                    Size { width: 10.0, height: 10.0 }
+                }),
+            )
+            .unwrap();
+        let fast = taffy.new_leaf(FlexboxLayout::default()).unwrap();
+        let root = taffy.new_with_children(FlexboxLayout::default(), &[slow, fast]).unwrap();
+        assert!(taffy.last_timings().is_empty());
+
+        taffy.compute_layout(root, Size::NONE).unwrap();
+
+        let timings = taffy.last_timings();
+        assert_eq!(timings.iter().map(|(node, _)| *node).collect::<Vec<_>>(), vec![root, slow, fast]);
+        // The time of the root includes that of its children
+        assert!(timings[1].1 >= Duration::from_millis(1));
+        assert!(timings[0].1 >= timings[1].1 + timings[2].1);
+    }
+
     #[test]
     fn measure_func_is_send_and_sync() {
         fn is_send_and_sync<T: Send + Sync>() {}
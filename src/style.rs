@@ -1,13 +1,18 @@
 //! A representation of [CSS layout properties](https://css-tricks.com/snippets/css/a-guide-to-flexbox/) in Rust, used for flexbox layout
 
+use crate::error::InvalidStyleValue;
 use crate::geometry::{Rect, Size};
+use crate::sys::abs;
+use core::fmt;
+use core::ops::{Add, Mul, Sub};
+use core::str::FromStr;
 
 /// How [`Nodes`](crate::node::Node) are aligned relative to the cross axis
 ///
 /// The default behavior is [`AlignItems::Stretch`].
 ///
 /// [Specification](https://www.w3.org/TR/css-flexbox-1/#align-items-property)
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AlignItems {
     /// Items are packed toward the start of the cross axis
@@ -19,15 +24,10 @@ pub enum AlignItems {
     /// Items are aligned such as their baselines align
     Baseline,
     /// Stretch to fill the container
+    #[default]
     Stretch,
 }
 
-impl Default for AlignItems {
-    fn default() -> Self {
-        Self::Stretch
-    }
-}
-
 /// Overrides the inherited [`AlignItems`] behavior for this node.
 ///
 /// The behavior of any child nodes will be controlled by this node's [`AlignItems`] value.
@@ -35,10 +35,11 @@ impl Default for AlignItems {
 /// The default behavior is [`AlignSelf::Auto`].
 ///
 /// [Specification](https://www.w3.org/TR/css-flexbox-1/#align-items-property)
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AlignSelf {
     /// Inherits the [`AlignItems`] behavior of the parent
+    #[default]
     Auto,
     /// Items are packed toward the start of the cross axis
     FlexStart,
@@ -52,18 +53,12 @@ pub enum AlignSelf {
     Stretch,
 }
 
-impl Default for AlignSelf {
-    fn default() -> Self {
-        Self::Auto
-    }
-}
-
 /// Sets the distribution of space between and around content items along the cross-axis
 ///
 /// The default value is [`AlignContent::Stretch`].
 ///
 /// [Specification](https://www.w3.org/TR/css-flexbox-1/#align-content-property)
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AlignContent {
     /// Items are packed toward the start of the cross axis
@@ -73,6 +68,7 @@ pub enum AlignContent {
     /// Items are packed along the center of the cross axis
     Center,
     /// Distribute items evenly, but stretch them to fill the container
+    #[default]
     Stretch,
     /// Distribute items evenly, such that the first and last item are aligned with the edges
     SpaceBetween,
@@ -81,30 +77,19 @@ pub enum AlignContent {
     SpaceAround,
 }
 
-impl Default for AlignContent {
-    fn default() -> Self {
-        Self::Stretch
-    }
-}
-
 /// Sets the layout used for the children of this node
 ///
 /// [`Display::Flex`] is the default value.
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Display {
     /// The children will follow the flexbox layout algorithm
+    #[default]
     Flex,
     /// The children will not be laid out, and will follow absolute positioning
     None,
 }
 
-impl Default for Display {
-    fn default() -> Self {
-        Self::Flex
-    }
-}
-
 /// The direction of the flexbox layout main axis.
 ///
 /// There are always two perpendicular layout axes: main (or primary) and cross (or secondary).
@@ -116,12 +101,13 @@ impl Default for Display {
 /// The default behavior is [`FlexDirection::Row`].
 ///
 /// [Specification](https://www.w3.org/TR/css-flexbox-1/#flex-direction-property)
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FlexDirection {
     /// Defines +x as the main axis
     ///
     /// Items will be added from left to right in a row.
+    #[default]
     Row,
     /// Defines +y as the main axis
     ///
@@ -137,12 +123,6 @@ pub enum FlexDirection {
     ColumnReverse,
 }
 
-impl Default for FlexDirection {
-    fn default() -> Self {
-        Self::Row
-    }
-}
-
 impl FlexDirection {
     #[inline]
     /// Is the direction [`FlexDirection::Row`] or [`FlexDirection::RowReverse`]?
@@ -168,10 +148,11 @@ impl FlexDirection {
 /// The default value is [`JustifyContent::FlexStart`].
 ///
 /// [Specification](https://www.w3.org/TR/css-flexbox-1/#justify-content-property)
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum JustifyContent {
     /// Items are packed toward the start of the main axis
+    #[default]
     FlexStart,
     /// Items are packed toward the end of the main axis
     FlexEnd,
@@ -179,20 +160,15 @@ pub enum JustifyContent {
     Center,
     /// Distribute items evenly, such that the first and last item are aligned with the edges
     SpaceBetween,
-    /// Distribute items evenly,
-    /// such that the space between items is twice same as the space between the first and last item and the edges
+    /// Distribute items evenly, such that each item has the same amount of space on both of its sides
+    ///
+    /// The space between two items is therefore twice the space between the first and last item and the edges.
     SpaceAround,
     /// Distribute items evenly,
     /// such that the space between items is the same as the space between the first and last item and the edges
     SpaceEvenly,
 }
 
-impl Default for JustifyContent {
-    fn default() -> Self {
-        Self::FlexStart
-    }
-}
-
 /// The positioning strategy for this item.
 ///
 /// This controls both how the origin is determined for the [`Style::position`] field,
@@ -202,11 +178,12 @@ impl Default for JustifyContent {
 /// which can be unintuitive.
 ///
 /// [`PositionType::Relative`] is the default value, in contrast to the default behavior in CSS.
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PositionType {
     /// The offset is computed relative to the final position given by the layout algorithm.
     /// Offsets do not affect the position of any other items; they are effectively a correction factor applied at the end.
+    #[default]
     Relative,
     /// The offset is computed relative to this item's closest positioned ancestor, if any.
     /// Otherwise, it is placed relative to the origin.
@@ -216,21 +193,16 @@ pub enum PositionType {
     Absolute,
 }
 
-impl Default for PositionType {
-    fn default() -> Self {
-        Self::Relative
-    }
-}
-
 /// Controls whether flex items are forced onto one line or can wrap onto multiple lines.
 ///
 /// Defaults to [`FlexWrap::NoWrap`]
 ///
 /// [Specification](https://www.w3.org/TR/css-flexbox-1/#flex-wrap-property)
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FlexWrap {
     /// Items will not wrap and stay on a single line
+    #[default]
     NoWrap,
     /// Items will wrap according to this item's [`FlexDirection`]
     Wrap,
@@ -238,20 +210,90 @@ pub enum FlexWrap {
     WrapReverse,
 }
 
-impl Default for FlexWrap {
-    fn default() -> Self {
-        Self::NoWrap
-    }
+/// Implements [`FromStr`] for a keyword enum, accepting the CSS spelling of each variant
+macro_rules! impl_from_css_keyword {
+    ($ty:ident { $($keyword:literal => $variant:ident),+ $(,)? }) => {
+        /// Parses the CSS keyword of each variant, such as `flex-start`
+        impl FromStr for $ty {
+            type Err = InvalidStyleValue;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s.trim() {
+                    $($keyword => Ok($ty::$variant),)+
+                    _ => Err(InvalidStyleValue { type_name: stringify!($ty) }),
+                }
+            }
+        }
+    };
 }
 
+impl_from_css_keyword!(AlignItems {
+    "flex-start" => FlexStart,
+    "flex-end" => FlexEnd,
+    "center" => Center,
+    "baseline" => Baseline,
+    "stretch" => Stretch,
+});
+
+impl_from_css_keyword!(AlignSelf {
+    "auto" => Auto,
+    "flex-start" => FlexStart,
+    "flex-end" => FlexEnd,
+    "center" => Center,
+    "baseline" => Baseline,
+    "stretch" => Stretch,
+});
+
+impl_from_css_keyword!(AlignContent {
+    "flex-start" => FlexStart,
+    "flex-end" => FlexEnd,
+    "center" => Center,
+    "stretch" => Stretch,
+    "space-between" => SpaceBetween,
+    "space-around" => SpaceAround,
+});
+
+impl_from_css_keyword!(Display {
+    "flex" => Flex,
+    "none" => None,
+});
+
+impl_from_css_keyword!(FlexDirection {
+    "row" => Row,
+    "column" => Column,
+    "row-reverse" => RowReverse,
+    "column-reverse" => ColumnReverse,
+});
+
+impl_from_css_keyword!(JustifyContent {
+    "flex-start" => FlexStart,
+    "flex-end" => FlexEnd,
+    "center" => Center,
+    "space-between" => SpaceBetween,
+    "space-around" => SpaceAround,
+    "space-evenly" => SpaceEvenly,
+});
+
+impl_from_css_keyword!(PositionType {
+    "relative" => Relative,
+    "absolute" => Absolute,
+});
+
+impl_from_css_keyword!(FlexWrap {
+    "nowrap" => NoWrap,
+    "wrap" => Wrap,
+    "wrap-reverse" => WrapReverse,
+});
+
 /// A unit of linear measurement
 ///
 /// This is commonly combined with [`Rect`], [`Point`](crate::geometry::Point) and [`Size<T>`].
 /// The default value is [`Dimension::Undefined`].
-#[derive(Copy, Clone, PartialEq, Debug)]
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Dimension {
     /// The dimension is not given
+    #[default]
     Undefined,
     /// The dimension should be automatically computed
     Auto,
@@ -261,12 +303,16 @@ pub enum Dimension {
     Points(f32),
     /// The dimension is stored in percentage relative to the parent item.
     Percent(f32),
-}
-
-impl Default for Dimension {
-    fn default() -> Self {
-        Self::Undefined
-    }
+    /// The dimension is the min-content size of the node, the smallest it can be made by wrapping its content
+    ///
+    /// This is currently only supported by [`FlexboxLayout::flex_basis`], and by [`FlexboxLayout::min_size`] and
+    /// [`FlexboxLayout::max_size`] of flex items. Other properties can't resolve it to a length.
+    MinContent,
+    /// The dimension is the max-content size of the node, its size when given unlimited space
+    ///
+    /// This is currently only supported by [`FlexboxLayout::flex_basis`], and by [`FlexboxLayout::min_size`] and
+    /// [`FlexboxLayout::max_size`] of flex items. Other properties can't resolve it to a length.
+    MaxContent,
 }
 
 impl Dimension {
@@ -274,15 +320,246 @@ impl Dimension {
     pub(crate) fn is_defined(self) -> bool {
         matches!(self, Dimension::Points(_) | Dimension::Percent(_))
     }
+
+    /// Multiplies [`Dimension::Points`] values by `factor`, leaving all other variants unchanged
+    pub(crate) fn scaled(self, factor: f32) -> Dimension {
+        match self {
+            Dimension::Points(points) => Dimension::Points(points * factor),
+            _ => self,
+        }
+    }
+
+    /// Is this a zero length, either [`Dimension::Points(0.0)`](Dimension::Points) or [`Dimension::Percent(0.0)`](Dimension::Percent)?
+    ///
+    /// [`Dimension::Auto`] and [`Dimension::Undefined`] are never zero, as their size depends on the layout.
+    #[must_use]
+    pub fn is_zero(self) -> bool {
+        matches!(self, Dimension::Points(value) | Dimension::Percent(value) if value == 0.0)
+    }
+
+    /// Offsets a [`Dimension::Points`] value by `delta` points
+    ///
+    /// There is no way to express the sum of a length and a percentage or a keyword, so every other variant
+    /// is returned unchanged, like the mismatched kinds of [`Add`].
+    #[must_use]
+    pub fn add_points(self, delta: f32) -> Dimension {
+        match self {
+            Dimension::Points(points) => Dimension::Points(points + delta),
+            _ => self,
+        }
+    }
+
+    /// Are these dimensions of the same kind, with values that differ by no more than `epsilon`?
+    #[must_use]
+    pub fn approx_eq(&self, other: &Dimension, epsilon: f32) -> bool {
+        match (self, other) {
+            (Dimension::Points(a), Dimension::Points(b)) | (Dimension::Percent(a), Dimension::Percent(b)) => {
+                approx_eq_f32(*a, *b, epsilon)
+            }
+            _ => self == other,
+        }
+    }
 }
 
-impl Default for Rect<Dimension> {
-    fn default() -> Self {
-        Self { start: Default::default(), end: Default::default(), top: Default::default(), bottom: Default::default() }
+/// Do `a` and `b` differ by no more than `epsilon`?
+fn approx_eq_f32(a: f32, b: f32, epsilon: f32) -> bool {
+    a == b || abs(a - b) <= epsilon
+}
+
+/// Formats the dimension as a CSS-like string, such as `10px`, `50%` or `auto`
+impl fmt::Display for Dimension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Dimension::Undefined => f.write_str("undefined"),
+            Dimension::Auto => f.write_str("auto"),
+            Dimension::Points(points) => write!(f, "{}px", points),
+            Dimension::Percent(fraction) => {
+                write_percentage(f, *fraction)?;
+                f.write_str("%")
+            }
+            Dimension::MinContent => f.write_str("min-content"),
+            Dimension::MaxContent => f.write_str("max-content"),
+        }
+    }
+}
+
+/// Parses the CSS-like strings produced by the [`Display`](fmt::Display) implementation
+impl FromStr for Dimension {
+    type Err = InvalidStyleValue;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = InvalidStyleValue { type_name: "Dimension" };
+        let s = s.trim();
+
+        match s {
+            "undefined" => Ok(Dimension::Undefined),
+            "auto" => Ok(Dimension::Auto),
+            "min-content" => Ok(Dimension::MinContent),
+            "max-content" => Ok(Dimension::MaxContent),
+            _ => {
+                if let Some(points) = s.strip_suffix("px") {
+                    points.trim_end().parse().map(Dimension::Points).map_err(|_| invalid)
+                } else if let Some(percent) = s.strip_suffix('%') {
+                    parse_percentage(percent.trim_end()).map(Dimension::Percent).ok_or(invalid)
+                } else {
+                    Err(invalid)
+                }
+            }
+        }
+    }
+}
+
+/// A fixed-size buffer for formatting a single number without allocating
+struct NumberBuffer {
+    /// The formatted bytes, of which only the first `len` are used
+    bytes: [u8; 64],
+    /// The number of bytes written so far
+    len: usize,
+}
+
+impl NumberBuffer {
+    /// Creates an empty buffer
+    fn new() -> Self {
+        NumberBuffer { bytes: [0; 64], len: 0 }
+    }
+
+    /// The text written so far
+    fn as_str(&self) -> &str {
+        // Only whole `&str`s are ever copied in, so this can't split a character
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+impl fmt::Write for NumberBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.bytes.len() {
+            return Err(fmt::Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Writes `fraction` as a percentage, without its `%` suffix
+///
+/// Rather than multiplying by 100, which can round to a value such as `7.000000000000001`,
+/// the decimal point of the shortest representation of `fraction` is moved two places to the right.
+/// [`parse_percentage`] moves it back, so every fraction is read back exactly.
+fn write_percentage(f: &mut fmt::Formatter<'_>, fraction: f32) -> fmt::Result {
+    use fmt::Write;
+
+    let mut buffer = NumberBuffer::new();
+    if !fraction.is_finite() || write!(buffer, "{}", fraction.abs()).is_err() {
+        return write!(f, "{}", fraction * 100.0);
+    }
+
+    let (integer, decimals) = buffer.as_str().split_once('.').unwrap_or((buffer.as_str(), ""));
+    let (shifted, decimals) = decimals.split_at(decimals.len().min(2));
+
+    let mut digits = NumberBuffer::new();
+    write!(digits, "{}{:0<2}", integer, shifted)?;
+    let integer = digits.as_str().trim_start_matches('0');
+
+    if fraction.is_sign_negative() {
+        f.write_str("-")?;
+    }
+    f.write_str(if integer.is_empty() { "0" } else { integer })?;
+    if !decimals.is_empty() {
+        write!(f, ".{}", decimals)?;
+    }
+    Ok(())
+}
+
+/// Parses a percentage written by [`write_percentage`] into a fraction
+///
+/// The number is parsed with its decimal point moved two places to the left, rather than divided by 100,
+/// so that it is rounded only once. Returns `None` if `percent` isn't a number.
+fn parse_percentage(percent: &str) -> Option<f32> {
+    use fmt::Write;
+
+    let value: f32 = percent.parse().ok()?;
+
+    // Numbers that aren't finite, already have an exponent or are too long to copy are divided instead
+    let mut buffer = NumberBuffer::new();
+    if !value.is_finite() || percent.contains(['e', 'E']) || write!(buffer, "{}e-2", percent).is_err() {
+        return Some(value / 100.0);
+    }
+    buffer.as_str().parse().ok()
+}
+
+/// Adds two dimensions of the same kind
+///
+/// `Points` can be added to `Points`, and `Percent` to `Percent`.
+/// For any other combination the left-hand side is returned unchanged, as there is no meaningful sum.
+impl Add for Dimension {
+    type Output = Dimension;
+
+    fn add(self, rhs: Dimension) -> Dimension {
+        match (self, rhs) {
+            (Dimension::Points(lhs), Dimension::Points(rhs)) => Dimension::Points(lhs + rhs),
+            (Dimension::Percent(lhs), Dimension::Percent(rhs)) => Dimension::Percent(lhs + rhs),
+            _ => self,
+        }
+    }
+}
+
+/// Subtracts a dimension of the same kind
+///
+/// As with [`Add`], mismatched kinds return the left-hand side unchanged.
+impl Sub for Dimension {
+    type Output = Dimension;
+
+    fn sub(self, rhs: Dimension) -> Dimension {
+        match (self, rhs) {
+            (Dimension::Points(lhs), Dimension::Points(rhs)) => Dimension::Points(lhs - rhs),
+            (Dimension::Percent(lhs), Dimension::Percent(rhs)) => Dimension::Percent(lhs - rhs),
+            _ => self,
+        }
+    }
+}
+
+/// Scales `Points` and `Percent` values, leaving `Auto` and `Undefined` unchanged
+impl Mul<f32> for Dimension {
+    type Output = Dimension;
+
+    fn mul(self, rhs: f32) -> Dimension {
+        match self {
+            Dimension::Points(points) => Dimension::Points(points * rhs),
+            Dimension::Percent(fraction) => Dimension::Percent(fraction * rhs),
+            _ => self,
+        }
     }
 }
 
 impl Rect<Dimension> {
+    /// Multiplies each [`Dimension::Points`] side by `factor`
+    fn scaled(self, factor: f32) -> Rect<Dimension> {
+        Rect {
+            start: self.start.scaled(factor),
+            end: self.end.scaled(factor),
+            top: self.top.scaled(factor),
+            bottom: self.bottom.scaled(factor),
+        }
+    }
+
+    /// Compares each side with [`Dimension::approx_eq`]
+    fn approx_eq(&self, other: &Rect<Dimension>, epsilon: f32) -> bool {
+        self.start.approx_eq(&other.start, epsilon)
+            && self.end.approx_eq(&other.end, epsilon)
+            && self.top.approx_eq(&other.top, epsilon)
+            && self.bottom.approx_eq(&other.bottom, epsilon)
+    }
+
+    /// Is every side zero, as defined by [`Dimension::is_zero`]?
+    ///
+    /// This is useful for skipping the rendering of empty borders or padding.
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.start.is_zero() && self.end.is_zero() && self.top.is_zero() && self.bottom.is_zero()
+    }
+
     /// Generates a [`Rect<Dimension>`] using [`Dimension::Points`] values for `start` and `top`
     #[must_use]
     pub fn top_from_points(start: f32, top: f32) -> Rect<Dimension> {
@@ -342,12 +619,177 @@ impl Rect<Dimension> {
     }
 }
 
+/// Sizes default to [`Dimension::Auto`] rather than to [`Dimension::default`],
+/// which is why [`Size`] has no generic [`Default`] implementation unlike [`Rect`]
 impl Default for Size<Dimension> {
     fn default() -> Self {
         Self { width: Dimension::Auto, height: Dimension::Auto }
     }
 }
 
+/// A field of [`FlexboxLayout`], as reported by a [`StyleDiff`]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum StyleField {
+    /// [`FlexboxLayout::display`]
+    Display,
+    /// [`FlexboxLayout::position_type`]
+    PositionType,
+    /// [`FlexboxLayout::flex_direction`]
+    FlexDirection,
+    /// [`FlexboxLayout::flex_wrap`]
+    FlexWrap,
+    /// [`FlexboxLayout::align_items`]
+    AlignItems,
+    /// [`FlexboxLayout::align_self`]
+    AlignSelf,
+    /// [`FlexboxLayout::align_content`]
+    AlignContent,
+    /// [`FlexboxLayout::justify_content`]
+    JustifyContent,
+    /// [`FlexboxLayout::position`]
+    Position,
+    /// [`FlexboxLayout::margin`]
+    Margin,
+    /// [`FlexboxLayout::padding`]
+    Padding,
+    /// [`FlexboxLayout::border`]
+    Border,
+    /// [`FlexboxLayout::flex_grow`]
+    FlexGrow,
+    /// [`FlexboxLayout::flex_shrink`]
+    FlexShrink,
+    /// [`FlexboxLayout::flex_basis`]
+    FlexBasis,
+    /// [`FlexboxLayout::size`]
+    Size,
+    /// [`FlexboxLayout::min_size`]
+    MinSize,
+    /// [`FlexboxLayout::max_size`]
+    MaxSize,
+    /// [`FlexboxLayout::aspect_ratio`]
+    AspectRatio,
+    /// [`FlexboxLayout::collapse_margins`]
+    CollapseMargins,
+    /// [`FlexboxLayout::order`]
+    Order,
+    /// [`FlexboxLayout::line_min_cross_size`]
+    LineMinCrossSize,
+    /// [`FlexboxLayout::gap`]
+    Gap,
+}
+
+impl StyleField {
+    /// Every field of [`FlexboxLayout`], in declaration order
+    pub const ALL: [StyleField; 23] = [
+        StyleField::Display,
+        StyleField::PositionType,
+        StyleField::FlexDirection,
+        StyleField::FlexWrap,
+        StyleField::AlignItems,
+        StyleField::AlignSelf,
+        StyleField::AlignContent,
+        StyleField::JustifyContent,
+        StyleField::Position,
+        StyleField::Margin,
+        StyleField::Padding,
+        StyleField::Border,
+        StyleField::FlexGrow,
+        StyleField::FlexShrink,
+        StyleField::FlexBasis,
+        StyleField::Size,
+        StyleField::MinSize,
+        StyleField::MaxSize,
+        StyleField::AspectRatio,
+        StyleField::CollapseMargins,
+        StyleField::Order,
+        StyleField::LineMinCrossSize,
+        StyleField::Gap,
+    ];
+
+    /// The name of the field, as written in [`FlexboxLayout`]
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            StyleField::Display => "display",
+            StyleField::PositionType => "position_type",
+            StyleField::FlexDirection => "flex_direction",
+            StyleField::FlexWrap => "flex_wrap",
+            StyleField::AlignItems => "align_items",
+            StyleField::AlignSelf => "align_self",
+            StyleField::AlignContent => "align_content",
+            StyleField::JustifyContent => "justify_content",
+            StyleField::Position => "position",
+            StyleField::Margin => "margin",
+            StyleField::Padding => "padding",
+            StyleField::Border => "border",
+            StyleField::FlexGrow => "flex_grow",
+            StyleField::FlexShrink => "flex_shrink",
+            StyleField::FlexBasis => "flex_basis",
+            StyleField::Size => "size",
+            StyleField::MinSize => "min_size",
+            StyleField::MaxSize => "max_size",
+            StyleField::AspectRatio => "aspect_ratio",
+            StyleField::CollapseMargins => "collapse_margins",
+            StyleField::Order => "order",
+            StyleField::LineMinCrossSize => "line_min_cross_size",
+            StyleField::Gap => "gap",
+        }
+    }
+}
+
+impl fmt::Display for StyleField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The set of fields that differ between two [`FlexboxLayout`]s
+///
+/// This is created by [`FlexboxLayout::diff`], and is useful for finding out why a relayout happened.
+/// It is displayed as a comma-separated list of field names, such as `padding, flex_grow`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct StyleDiff {
+    /// One bit per [`StyleField`], indexed by its position in [`StyleField::ALL`]
+    changed: u32,
+}
+
+impl StyleDiff {
+    /// Are the two styles identical?
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.changed == 0
+    }
+
+    /// The number of fields that differ
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.changed.count_ones() as usize
+    }
+
+    /// Does `field` differ between the two styles?
+    #[must_use]
+    pub fn contains(&self, field: StyleField) -> bool {
+        self.changed & (1 << field as u32) != 0
+    }
+
+    /// The fields that differ, in declaration order
+    pub fn iter(&self) -> impl Iterator<Item = StyleField> + '_ {
+        StyleField::ALL.into_iter().filter(|field| self.contains(*field))
+    }
+}
+
+impl fmt::Display for StyleDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, field) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(field.name())?;
+        }
+        Ok(())
+    }
+}
+
 /// The flexbox layout information for a single [`Node`](crate::node::Node).
 ///
 /// The most important idea in flexbox is the notion of a "main" and "cross" axis, which are always perpendicular to each other.
@@ -362,55 +804,123 @@ impl Default for Size<Dimension> {
 /// this [introduction to the box model](https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_Box_Model/Introduction_to_the_CSS_box_model).
 ///
 /// If the behavior does not match the flexbox layout algorithm on the web, please file a bug!
+///
+/// When serialized with the `serde` feature, fields that hold their default value are omitted.
 #[derive(Copy, Clone, PartialEq, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(default))]
 pub struct FlexboxLayout {
     /// What layout strategy should be used?
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "skip_default::is_default"))]
     pub display: Display,
     /// What should the `position` value of this struct use as a base offset?
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "skip_default::is_default"))]
     pub position_type: PositionType,
     /// Which direction does the main axis flow in?
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "skip_default::is_default"))]
     pub flex_direction: FlexDirection,
     /// Should elements wrap, or stay in a single line?
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "skip_default::is_default"))]
     pub flex_wrap: FlexWrap,
     /// How should items be aligned relative to the cross axis?
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "skip_default::is_default"))]
     pub align_items: AlignItems,
     /// Should this item violate the cross axis alignment specified by its parent's [`AlignItems`]?
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "skip_default::is_default"))]
     pub align_self: AlignSelf,
     /// How should content contained within this item be aligned relative to the cross axis?
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "skip_default::is_default"))]
     pub align_content: AlignContent,
     /// How should items be aligned relative to the main axis?
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "skip_default::is_default"))]
     pub justify_content: JustifyContent,
     /// How should the position of this element be tweaked relative to the layout defined?
+    ///
+    /// Percentages are resolved against the matching axis of the container's inner size,
+    /// and are treated as zero when that axis is indefinite.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "skip_default::is_default"))]
     pub position: Rect<Dimension>,
     /// How large should the margin be on each side?
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "skip_default::is_default"))]
     pub margin: Rect<Dimension>,
     /// How large should the padding be on each side?
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "skip_default::is_default"))]
     pub padding: Rect<Dimension>,
     /// How large should the border be on each side?
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "skip_default::is_default"))]
     pub border: Rect<Dimension>,
     /// The relative rate at which this item grows when it is expanding to fill space
     ///
     /// 0.0 is the default value, and this value must be positive.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "skip_default::is_default"))]
     pub flex_grow: f32,
     /// The relative rate at which this item shrinks when it is contracting to fit into space
     ///
     /// 1.0 is the default value, and this value must be positive.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "skip_default::is_default_flex_shrink"))]
     pub flex_shrink: f32,
     /// Sets the initial main axis size of the item
+    ///
+    /// [`Dimension::Undefined`] is treated identically to [`Dimension::Auto`]:
+    /// the item falls back to its `size`, aspect ratio or content size along the main axis.
+    /// [`Dimension::MinContent`] and [`Dimension::MaxContent`] use the matching intrinsic size of the item along the main axis.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "skip_default::is_default_flex_basis"))]
     pub flex_basis: Dimension,
     /// Sets the initial size of the item
     // TODO: why does this exist as distinct from flex_basis? How do they interact?
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "skip_default::is_default"))]
     pub size: Size<Dimension>,
     /// Controls the minimum size of the item
+    ///
+    /// On the main axis of a flex item, [`Dimension::Auto`] and [`Dimension::Undefined`] are an automatic minimum
+    /// based on the size of its content, which an explicit value (even zero) replaces. Elsewhere, they set no lower bound.
+    /// [`Dimension::MinContent`] and [`Dimension::MaxContent`] use the matching intrinsic size of a flex item.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "skip_default::is_default"))]
     pub min_size: Size<Dimension>,
     /// Controls the maximum size of the item
+    ///
+    /// [`Dimension::Auto`] sets no upper bound.
+    /// [`Dimension::MinContent`] and [`Dimension::MaxContent`] use the matching intrinsic size of a flex item.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "skip_default::is_default"))]
     pub max_size: Size<Dimension>,
     /// Sets the preferred aspect ratio for the item
     ///
     /// The ratio is calculated as width divided by height.
+    /// Ratios that are zero, negative or not finite are ignored.
+    ///
+    /// When the ratio cannot be satisfied together with the min and max sizes, the result is resolved in a fixed order:
+    /// the cross size is clamped first, with the min size winning over a conflicting max size,
+    /// and the main axis min and max sizes then take precedence over the ratio.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "skip_default::is_default"))]
     pub aspect_ratio: Option<f32>,
+    /// Should the margins between adjacent items collapse into the larger of the two, instead of adding up?
+    ///
+    /// This only affects column containers, where it allows stacking items like blocks,
+    /// and deviates from the flexbox spec, in which margins never collapse.
+    /// Items are only collapsed with their neighbours on the same flex line, and negative margins are not collapsed.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "skip_default::is_default"))]
+    pub collapse_margins: bool,
+    /// Where should this item be placed among the items of its flex container?
+    ///
+    /// Items are laid out in ascending order, and the sort is stable: items with equal values keep their source order.
+    /// This only changes the visual placement; [`Layout::order`](crate::layout::Layout::order) remains the source order.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "skip_default::is_default"))]
+    pub order: i32,
+    /// What is the smallest cross size each flex line of this container can have?
+    ///
+    /// Lines whose items are all shorter than this are grown to it, so sparse lines of a wrapping container
+    /// keep a consistent height. Percentages are resolved against the container's inner cross size.
+    /// This has no effect on a single-line container with a definite cross size, whose line always fills it.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "skip_default::is_default_line_min_cross_size"))]
+    pub line_min_cross_size: Dimension,
+    /// How much space should be left between adjacent items and between adjacent flex lines?
+    ///
+    /// The width is the gap between columns, and the height the gap between rows:
+    /// in a row container, items on a line are spaced by the width and wrapped lines by the height.
+    /// No gap is added before the first or after the last item or line.
+    /// Percentages are resolved against the container's inner size in the same axis, or are zero if it is indefinite.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "skip_default::is_default_gap"))]
+    pub gap: Size<Dimension>,
 }
 
 impl Default for FlexboxLayout {
@@ -435,11 +945,228 @@ impl Default for FlexboxLayout {
             min_size: Default::default(),
             max_size: Default::default(),
             aspect_ratio: Default::default(),
+            collapse_margins: false,
+            order: 0,
+            line_min_cross_size: Dimension::Undefined,
+            gap: Size::from_points(0.0, 0.0),
         }
     }
 }
 
+/// Predicates that omit the fields of a [`FlexboxLayout`] holding their default value when serializing
+///
+/// The omitted fields are restored from [`FlexboxLayout::default`] when deserializing, so styles round-trip.
+#[cfg(feature = "serde")]
+mod skip_default {
+    use super::{Dimension, FlexboxLayout};
+    use crate::geometry::Size;
+
+    /// Is `value` the default of its type, which is also its default in a [`FlexboxLayout`]?
+    pub(super) fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+        *value == T::default()
+    }
+
+    /// Is `value` the default [`FlexboxLayout::flex_shrink`]?
+    pub(super) fn is_default_flex_shrink(value: &f32) -> bool {
+        *value == FlexboxLayout::default().flex_shrink
+    }
+
+    /// Is `value` the default [`FlexboxLayout::flex_basis`]?
+    pub(super) fn is_default_flex_basis(value: &Dimension) -> bool {
+        *value == FlexboxLayout::default().flex_basis
+    }
+
+    /// Is `value` the default [`FlexboxLayout::line_min_cross_size`]?
+    pub(super) fn is_default_line_min_cross_size(value: &Dimension) -> bool {
+        *value == FlexboxLayout::default().line_min_cross_size
+    }
+
+    /// Is `value` the default [`FlexboxLayout::gap`]?
+    pub(super) fn is_default_gap(value: &Size<Dimension>) -> bool {
+        *value == FlexboxLayout::default().gap
+    }
+}
+
 impl FlexboxLayout {
+    /// Creates a default style whose items are laid out in a [`FlexDirection::Row`]
+    #[must_use]
+    pub fn row() -> Self {
+        FlexboxLayout { flex_direction: FlexDirection::Row, ..Default::default() }
+    }
+
+    /// Creates a default style whose items are laid out in a [`FlexDirection::Column`]
+    #[must_use]
+    pub fn column() -> Self {
+        FlexboxLayout { flex_direction: FlexDirection::Column, ..Default::default() }
+    }
+
+    /// Returns this style with [`FlexWrap::Wrap`]
+    #[must_use]
+    pub fn wrap(self) -> Self {
+        FlexboxLayout { flex_wrap: FlexWrap::Wrap, ..self }
+    }
+
+    /// Returns this style with [`FlexWrap::NoWrap`]
+    #[must_use]
+    pub fn nowrap(self) -> Self {
+        FlexboxLayout { flex_wrap: FlexWrap::NoWrap, ..self }
+    }
+
+    /// Returns this style with [`FlexWrap::WrapReverse`]
+    #[must_use]
+    pub fn wrap_reverse(self) -> Self {
+        FlexboxLayout { flex_wrap: FlexWrap::WrapReverse, ..self }
+    }
+
+    /// Returns this style with its main axis reversed
+    ///
+    /// [`FlexDirection::Row`] and [`FlexDirection::RowReverse`] are swapped, as are [`FlexDirection::Column`] and [`FlexDirection::ColumnReverse`].
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        let flex_direction = match self.flex_direction {
+            FlexDirection::Row => FlexDirection::RowReverse,
+            FlexDirection::RowReverse => FlexDirection::Row,
+            FlexDirection::Column => FlexDirection::ColumnReverse,
+            FlexDirection::ColumnReverse => FlexDirection::Column,
+        };
+        FlexboxLayout { flex_direction, ..self }
+    }
+
+    /// Returns this style with its [`FlexboxLayout::aspect_ratio`] set to `ratio`
+    #[must_use]
+    pub fn with_aspect_ratio(self, ratio: f32) -> Self {
+        FlexboxLayout { aspect_ratio: Some(ratio), ..self }
+    }
+
+    /// Returns this style without an [`FlexboxLayout::aspect_ratio`]
+    #[must_use]
+    pub fn without_aspect_ratio(self) -> Self {
+        FlexboxLayout { aspect_ratio: None, ..self }
+    }
+
+    /// Returns a copy of this style with every [`Dimension::Points`] value multiplied by `factor`
+    ///
+    /// This is useful for applying a DPI or zoom factor to a style before layout.
+    /// [`Dimension::Percent`], [`Dimension::Auto`] and [`Dimension::Undefined`] values are left untouched.
+    #[must_use]
+    pub fn scaled(&self, factor: f32) -> FlexboxLayout {
+        FlexboxLayout {
+            position: self.position.scaled(factor),
+            margin: self.margin.scaled(factor),
+            padding: self.padding.scaled(factor),
+            border: self.border.scaled(factor),
+            flex_basis: self.flex_basis.scaled(factor),
+            size: self.size.map(|dimension| dimension.scaled(factor)),
+            min_size: self.min_size.map(|dimension| dimension.scaled(factor)),
+            max_size: self.max_size.map(|dimension| dimension.scaled(factor)),
+            line_min_cross_size: self.line_min_cross_size.scaled(factor),
+            gap: self.gap.map(|dimension| dimension.scaled(factor)),
+            ..*self
+        }
+    }
+
+    /// Is this style equal to `other`, allowing each floating point value to differ by up to `epsilon`?
+    ///
+    /// Enum fields must match exactly, and [`Dimension`]s must be of the same kind (see [`Dimension::approx_eq`]).
+    /// This is useful for deciding whether an interpolated style is close enough to the current one to skip a relayout.
+    #[must_use]
+    pub fn approx_eq(&self, other: &FlexboxLayout, epsilon: f32) -> bool {
+        let size_approx_eq = |a: Size<Dimension>, b: Size<Dimension>| {
+            a.width.approx_eq(&b.width, epsilon) && a.height.approx_eq(&b.height, epsilon)
+        };
+        let aspect_ratio_approx_eq = match (self.aspect_ratio, other.aspect_ratio) {
+            (Some(a), Some(b)) => approx_eq_f32(a, b, epsilon),
+            (a, b) => a == b,
+        };
+
+        self.display == other.display
+            && self.position_type == other.position_type
+            && self.flex_direction == other.flex_direction
+            && self.flex_wrap == other.flex_wrap
+            && self.align_items == other.align_items
+            && self.align_self == other.align_self
+            && self.align_content == other.align_content
+            && self.justify_content == other.justify_content
+            && self.position.approx_eq(&other.position, epsilon)
+            && self.margin.approx_eq(&other.margin, epsilon)
+            && self.padding.approx_eq(&other.padding, epsilon)
+            && self.border.approx_eq(&other.border, epsilon)
+            && approx_eq_f32(self.flex_grow, other.flex_grow, epsilon)
+            && approx_eq_f32(self.flex_shrink, other.flex_shrink, epsilon)
+            && self.flex_basis.approx_eq(&other.flex_basis, epsilon)
+            && size_approx_eq(self.size, other.size)
+            && size_approx_eq(self.min_size, other.min_size)
+            && size_approx_eq(self.max_size, other.max_size)
+            && aspect_ratio_approx_eq
+            && self.collapse_margins == other.collapse_margins
+            && self.order == other.order
+            && self.line_min_cross_size.approx_eq(&other.line_min_cross_size, epsilon)
+            && size_approx_eq(self.gap, other.gap)
+    }
+
+    /// Lists the fields that differ between this style and `other`
+    #[must_use]
+    pub fn diff(&self, other: &FlexboxLayout) -> StyleDiff {
+        let fields = [
+            (StyleField::Display, self.display != other.display),
+            (StyleField::PositionType, self.position_type != other.position_type),
+            (StyleField::FlexDirection, self.flex_direction != other.flex_direction),
+            (StyleField::FlexWrap, self.flex_wrap != other.flex_wrap),
+            (StyleField::AlignItems, self.align_items != other.align_items),
+            (StyleField::AlignSelf, self.align_self != other.align_self),
+            (StyleField::AlignContent, self.align_content != other.align_content),
+            (StyleField::JustifyContent, self.justify_content != other.justify_content),
+            (StyleField::Position, self.position != other.position),
+            (StyleField::Margin, self.margin != other.margin),
+            (StyleField::Padding, self.padding != other.padding),
+            (StyleField::Border, self.border != other.border),
+            (StyleField::FlexGrow, self.flex_grow != other.flex_grow),
+            (StyleField::FlexShrink, self.flex_shrink != other.flex_shrink),
+            (StyleField::FlexBasis, self.flex_basis != other.flex_basis),
+            (StyleField::Size, self.size != other.size),
+            (StyleField::MinSize, self.min_size != other.min_size),
+            (StyleField::MaxSize, self.max_size != other.max_size),
+            (StyleField::AspectRatio, self.aspect_ratio != other.aspect_ratio),
+            (StyleField::CollapseMargins, self.collapse_margins != other.collapse_margins),
+            (StyleField::Order, self.order != other.order),
+            (StyleField::LineMinCrossSize, self.line_min_cross_size != other.line_min_cross_size),
+            (StyleField::Gap, self.gap != other.gap),
+        ];
+
+        let changed = fields
+            .into_iter()
+            .filter(|(_, changed)| *changed)
+            .fold(0, |changed, (field, _)| changed | (1 << field as u32));
+
+        StyleDiff { changed }
+    }
+
+    /// Would laying out the inside of a node give the same results with either of these styles?
+    ///
+    /// Properties such as the node's own size, flex factors and `align_self` are resolved by its parent,
+    /// so changing only those invalidates the parent's layout but not the results cached for the node itself.
+    pub(crate) fn has_same_inner_layout(&self, other: &FlexboxLayout) -> bool {
+        self.display == other.display
+            && self.flex_direction == other.flex_direction
+            && self.flex_wrap == other.flex_wrap
+            && self.align_items == other.align_items
+            && self.align_content == other.align_content
+            && self.justify_content == other.justify_content
+            && self.margin == other.margin
+            && self.padding == other.padding
+            && self.border == other.border
+            && self.collapse_margins == other.collapse_margins
+            && self.line_min_cross_size == other.line_min_cross_size
+            && self.gap == other.gap
+    }
+
+    /// The [`FlexboxLayout::aspect_ratio`] used during layout
+    ///
+    /// Ratios that are zero, negative or not finite can't be applied, so they are ignored.
+    pub(crate) fn used_aspect_ratio(&self) -> Option<f32> {
+        self.aspect_ratio.filter(|ratio| ratio.is_finite() && *ratio > 0.0)
+    }
+
     /// If the `direction` is row-oriented, the min width. Otherwise the min height
     pub(crate) fn min_main_size(&self, direction: FlexDirection) -> Dimension {
         if direction.is_row() {
@@ -540,6 +1267,115 @@ impl FlexboxLayout {
     }
 }
 
+/// Serde helpers that represent [`Dimension`]s as compact, CSS-like strings
+///
+/// Use this with `#[serde(with = "taffy::style::compact")]` on fields of type [`Dimension`], [`Size<Dimension>`] or [`Rect<Dimension>`].
+/// For example, `Dimension::Points(10.0)` is represented as `"10px"` rather than `{"Points": 10.0}`.
+#[cfg(feature = "serde")]
+pub mod compact {
+    use super::Dimension;
+    use crate::geometry::{Rect, Size};
+    use core::fmt;
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    /// A [`Dimension`] that is (de)serialized as a string
+    #[derive(Default)]
+    struct CompactDimension(Dimension);
+
+    impl Default for Size<CompactDimension> {
+        fn default() -> Self {
+            Size { width: CompactDimension::default(), height: CompactDimension::default() }
+        }
+    }
+
+    impl Serialize for CompactDimension {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_str(&self.0)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for CompactDimension {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_str(CompactDimensionVisitor)
+        }
+    }
+
+    /// Parses a [`CompactDimension`] from a string
+    struct CompactDimensionVisitor;
+
+    impl<'de> de::Visitor<'de> for CompactDimensionVisitor {
+        type Value = CompactDimension;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a dimension such as \"10px\", \"50%\" or \"auto\"")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            value.parse().map(CompactDimension).map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+        }
+    }
+
+    /// Types made up of [`Dimension`]s that can be (de)serialized with [`compact`](self)
+    pub trait Compact: Sized {
+        /// Serializes `self`, representing each [`Dimension`] as a string
+        fn serialize_compact<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+        /// Deserializes a value whose [`Dimension`]s are represented as strings
+        fn deserialize_compact<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+    }
+
+    impl Compact for Dimension {
+        fn serialize_compact<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            CompactDimension(*self).serialize(serializer)
+        }
+
+        fn deserialize_compact<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            CompactDimension::deserialize(deserializer).map(|dimension| dimension.0)
+        }
+    }
+
+    impl Compact for Size<Dimension> {
+        fn serialize_compact<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.map(CompactDimension).serialize(serializer)
+        }
+
+        fn deserialize_compact<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Size::<CompactDimension>::deserialize(deserializer).map(|size| size.map(|dimension| dimension.0))
+        }
+    }
+
+    impl Compact for Rect<Dimension> {
+        fn serialize_compact<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Rect {
+                start: CompactDimension(self.start),
+                end: CompactDimension(self.end),
+                top: CompactDimension(self.top),
+                bottom: CompactDimension(self.bottom),
+            }
+            .serialize(serializer)
+        }
+
+        fn deserialize_compact<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Rect::<CompactDimension>::deserialize(deserializer).map(|rect| Rect {
+                start: rect.start.0,
+                end: rect.end.0,
+                top: rect.top.0,
+                bottom: rect.bottom.0,
+            })
+        }
+    }
+
+    /// Serializes `value`, representing each [`Dimension`] as a string
+    pub fn serialize<T: Compact, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_compact(serializer)
+    }
+
+    /// Deserializes a value whose [`Dimension`]s are represented as strings
+    pub fn deserialize<'de, T: Compact, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_compact(deserializer)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     mod test_flex_direction {
@@ -547,26 +1383,358 @@ mod tests {
 
         #[test]
         fn flex_direction_is_row() {
-            assert_eq!(FlexDirection::Row.is_row(), true);
-            assert_eq!(FlexDirection::RowReverse.is_row(), true);
-            assert_eq!(FlexDirection::Column.is_row(), false);
-            assert_eq!(FlexDirection::ColumnReverse.is_row(), false);
+            assert!(FlexDirection::Row.is_row());
+            assert!(FlexDirection::RowReverse.is_row());
+            assert!(!FlexDirection::Column.is_row());
+            assert!(!FlexDirection::ColumnReverse.is_row());
         }
 
         #[test]
         fn flex_direction_is_column() {
-            assert_eq!(FlexDirection::Row.is_column(), false);
-            assert_eq!(FlexDirection::RowReverse.is_column(), false);
-            assert_eq!(FlexDirection::Column.is_column(), true);
-            assert_eq!(FlexDirection::ColumnReverse.is_column(), true);
+            assert!(!FlexDirection::Row.is_column());
+            assert!(!FlexDirection::RowReverse.is_column());
+            assert!(FlexDirection::Column.is_column());
+            assert!(FlexDirection::ColumnReverse.is_column());
         }
 
         #[test]
         fn flex_direction_is_reverse() {
-            assert_eq!(FlexDirection::Row.is_reverse(), false);
-            assert_eq!(FlexDirection::RowReverse.is_reverse(), true);
-            assert_eq!(FlexDirection::Column.is_reverse(), false);
-            assert_eq!(FlexDirection::ColumnReverse.is_reverse(), true);
+            assert!(!FlexDirection::Row.is_reverse());
+            assert!(FlexDirection::RowReverse.is_reverse());
+            assert!(!FlexDirection::Column.is_reverse());
+            assert!(FlexDirection::ColumnReverse.is_reverse());
+        }
+    }
+
+    mod test_keywords {
+        use crate::error::InvalidStyleValue;
+        use crate::style::*;
+
+        #[test]
+        fn alignment() {
+            assert_eq!("flex-start".parse(), Ok(AlignItems::FlexStart));
+            assert_eq!("flex-end".parse(), Ok(AlignItems::FlexEnd));
+            assert_eq!("center".parse(), Ok(AlignItems::Center));
+            assert_eq!("baseline".parse(), Ok(AlignItems::Baseline));
+            assert_eq!("stretch".parse(), Ok(AlignItems::Stretch));
+
+            assert_eq!("auto".parse(), Ok(AlignSelf::Auto));
+            assert_eq!("flex-start".parse(), Ok(AlignSelf::FlexStart));
+            assert_eq!("flex-end".parse(), Ok(AlignSelf::FlexEnd));
+            assert_eq!("center".parse(), Ok(AlignSelf::Center));
+            assert_eq!("baseline".parse(), Ok(AlignSelf::Baseline));
+            assert_eq!("stretch".parse(), Ok(AlignSelf::Stretch));
+
+            assert_eq!("flex-start".parse(), Ok(AlignContent::FlexStart));
+            assert_eq!("flex-end".parse(), Ok(AlignContent::FlexEnd));
+            assert_eq!("center".parse(), Ok(AlignContent::Center));
+            assert_eq!("stretch".parse(), Ok(AlignContent::Stretch));
+            assert_eq!("space-between".parse(), Ok(AlignContent::SpaceBetween));
+            assert_eq!("space-around".parse(), Ok(AlignContent::SpaceAround));
+
+            assert_eq!("flex-start".parse(), Ok(JustifyContent::FlexStart));
+            assert_eq!("flex-end".parse(), Ok(JustifyContent::FlexEnd));
+            assert_eq!("center".parse(), Ok(JustifyContent::Center));
+            assert_eq!("space-between".parse(), Ok(JustifyContent::SpaceBetween));
+            assert_eq!("space-around".parse(), Ok(JustifyContent::SpaceAround));
+            assert_eq!("space-evenly".parse(), Ok(JustifyContent::SpaceEvenly));
+        }
+
+        #[test]
+        fn layout_modes() {
+            assert_eq!("flex".parse(), Ok(Display::Flex));
+            assert_eq!("none".parse(), Ok(Display::None));
+
+            assert_eq!("row".parse(), Ok(FlexDirection::Row));
+            assert_eq!("column".parse(), Ok(FlexDirection::Column));
+            assert_eq!("row-reverse".parse(), Ok(FlexDirection::RowReverse));
+            assert_eq!(" column-reverse ".parse(), Ok(FlexDirection::ColumnReverse));
+
+            assert_eq!("relative".parse(), Ok(PositionType::Relative));
+            assert_eq!("absolute".parse(), Ok(PositionType::Absolute));
+
+            assert_eq!("nowrap".parse(), Ok(FlexWrap::NoWrap));
+            assert_eq!("wrap".parse(), Ok(FlexWrap::Wrap));
+            assert_eq!("wrap-reverse".parse(), Ok(FlexWrap::WrapReverse));
+        }
+
+        #[test]
+        fn unknown_keyword() {
+            assert_eq!("space-evenly".parse::<AlignContent>(), Err(InvalidStyleValue { type_name: "AlignContent" }));
+            assert_eq!("FlexStart".parse::<JustifyContent>(), Err(InvalidStyleValue { type_name: "JustifyContent" }));
+            assert_eq!("".parse::<Display>(), Err(InvalidStyleValue { type_name: "Display" }));
+        }
+    }
+
+    mod test_dimension {
+        use crate::style::*;
+        use std::string::ToString;
+
+        #[test]
+        fn dimension_display() {
+            assert_eq!(Dimension::Undefined.to_string(), "undefined");
+            assert_eq!(Dimension::Auto.to_string(), "auto");
+            assert_eq!(Dimension::Points(10.0).to_string(), "10px");
+            assert_eq!(Dimension::Points(2.5).to_string(), "2.5px");
+            assert_eq!(Dimension::Percent(0.5).to_string(), "50%");
+            assert_eq!(Dimension::MinContent.to_string(), "min-content");
+            assert_eq!(Dimension::MaxContent.to_string(), "max-content");
+        }
+
+        #[test]
+        fn dimension_add_and_sub() {
+            assert_eq!(Dimension::Points(10.0) + Dimension::Points(5.0), Dimension::Points(15.0));
+            assert_eq!(Dimension::Percent(0.5) + Dimension::Percent(0.25), Dimension::Percent(0.75));
+            assert_eq!(Dimension::Points(10.0) - Dimension::Points(5.0), Dimension::Points(5.0));
+            assert_eq!(Dimension::Percent(0.5) - Dimension::Percent(0.25), Dimension::Percent(0.25));
+
+            // Mismatched kinds keep the left-hand side
+            assert_eq!(Dimension::Points(10.0) + Dimension::Percent(0.5), Dimension::Points(10.0));
+            assert_eq!(Dimension::Auto - Dimension::Points(5.0), Dimension::Auto);
+            assert_eq!(Dimension::Percent(0.5) + Dimension::Undefined, Dimension::Percent(0.5));
+        }
+
+        #[test]
+        fn dimension_add_points() {
+            assert_eq!(Dimension::Points(10.0).add_points(2.5), Dimension::Points(12.5));
+            assert_eq!(Dimension::Points(10.0).add_points(-15.0), Dimension::Points(-5.0));
+
+            // Only lengths in points can be offset
+            assert_eq!(Dimension::Percent(0.5).add_points(2.0), Dimension::Percent(0.5));
+            assert_eq!(Dimension::Auto.add_points(2.0), Dimension::Auto);
+            assert_eq!(Dimension::Undefined.add_points(2.0), Dimension::Undefined);
+            assert_eq!(Dimension::MinContent.add_points(2.0), Dimension::MinContent);
+            assert_eq!(Dimension::MaxContent.add_points(2.0), Dimension::MaxContent);
+        }
+
+        #[test]
+        fn dimension_mul() {
+            assert_eq!(Dimension::Points(10.0) * 2.0, Dimension::Points(20.0));
+            assert_eq!(Dimension::Percent(0.25) * 2.0, Dimension::Percent(0.5));
+            assert_eq!(Dimension::Auto * 2.0, Dimension::Auto);
+            assert_eq!(Dimension::Undefined * 2.0, Dimension::Undefined);
+        }
+
+        #[test]
+        fn dimension_from_str() {
+            assert_eq!("undefined".parse(), Ok(Dimension::Undefined));
+            assert_eq!("auto".parse(), Ok(Dimension::Auto));
+            assert_eq!("10px".parse(), Ok(Dimension::Points(10.0)));
+            assert_eq!(" -2.5 px ".parse(), Ok(Dimension::Points(-2.5)));
+            assert_eq!("50%".parse(), Ok(Dimension::Percent(0.5)));
+            assert_eq!("min-content".parse(), Ok(Dimension::MinContent));
+            assert_eq!("max-content".parse(), Ok(Dimension::MaxContent));
+            assert!("10".parse::<Dimension>().is_err());
+            assert!("px".parse::<Dimension>().is_err());
+            assert!("ten%".parse::<Dimension>().is_err());
+        }
+
+        #[test]
+        fn percent_display_round_trips() {
+            assert_eq!(Dimension::Percent(0.07).to_string(), "7%");
+            assert_eq!(Dimension::Percent(0.001234).to_string(), "0.1234%");
+            assert_eq!(Dimension::Percent(-1.5).to_string(), "-150%");
+            assert_eq!(Dimension::Percent(12.0).to_string(), "1200%");
+
+            let awkward =
+                [0.07, 0.1, 0.29, 0.3, 0.57, 1.0 / 3.0, 2.0 / 3.0, 1e-7, 123.456, f32::EPSILON, f32::MIN_POSITIVE];
+            let steps = (0..1000).map(|step| step as f32 / 1000.0);
+            let neighbours = (0..1000).map(|step| f32::from_bits(0.07f32.to_bits() + step));
+            for fraction in awkward.into_iter().chain(steps).chain(neighbours) {
+                for fraction in [fraction, -fraction] {
+                    let dimension = Dimension::Percent(fraction);
+                    assert_eq!(dimension.to_string().parse(), Ok(dimension), "{} did not round-trip", dimension);
+                }
+            }
+        }
+
+        #[test]
+        fn dimension_is_zero() {
+            assert!(Dimension::Points(0.0).is_zero());
+            assert!(Dimension::Points(-0.0).is_zero());
+            assert!(Dimension::Percent(0.0).is_zero());
+            assert!(!Dimension::Points(1.0).is_zero());
+            assert!(!Dimension::Percent(0.5).is_zero());
+            assert!(!Dimension::Auto.is_zero());
+            assert!(!Dimension::Undefined.is_zero());
+        }
+
+        #[test]
+        fn size_and_rect_is_zero() {
+            assert!(Size::from_points(0.0, 0.0).is_zero());
+            assert!(Size { width: Dimension::Points(0.0), height: Dimension::Percent(0.0) }.is_zero());
+            assert!(!Size::from_points(0.0, 1.0).is_zero());
+            assert!(!Size::AUTO.is_zero());
+            assert!(!Size::UNDEFINED.is_zero());
+
+            assert!(Rect::from_points(0.0, 0.0, 0.0, 0.0).is_zero());
+            assert!(!Rect::from_points(0.0, 0.0, 0.0, 1.0).is_zero());
+            assert!(!Rect::<Dimension>::default().is_zero());
+            assert!(!Rect { start: Dimension::Auto, ..Rect::from_points(0.0, 0.0, 0.0, 0.0) }.is_zero());
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    mod test_compact {
+        use crate::style::*;
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Compact {
+            #[serde(with = "crate::style::compact")]
+            basis: Dimension,
+            #[serde(with = "crate::style::compact")]
+            size: Size<Dimension>,
+            #[serde(with = "crate::style::compact")]
+            margin: Rect<Dimension>,
+        }
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Verbose {
+            basis: Dimension,
+            size: Size<Dimension>,
+            margin: Rect<Dimension>,
+        }
+
+        const BASIS: Dimension = Dimension::Points(10.0);
+        const SIZE: Size<Dimension> = Size { width: Dimension::Percent(0.5), height: Dimension::Auto };
+        const MARGIN: Rect<Dimension> = Rect {
+            start: Dimension::Points(1.0),
+            end: Dimension::Percent(0.25),
+            top: Dimension::Undefined,
+            bottom: Dimension::Auto,
+        };
+
+        #[test]
+        fn compact_round_trip() {
+            let value = Compact { basis: BASIS, size: SIZE, margin: MARGIN };
+            let json = serde_json::to_string(&value).unwrap();
+
+            assert_eq!(
+                json,
+                r#"{"basis":"10px","size":{"width":"50%","height":"auto"},"margin":{"start":"1px","end":"25%","top":"undefined","bottom":"auto"}}"#
+            );
+            assert_eq!(serde_json::from_str::<Compact>(&json).unwrap(), value);
+        }
+
+        #[test]
+        fn compact_matches_verbose() {
+            let verbose = Verbose { basis: BASIS, size: SIZE, margin: MARGIN };
+            let compact = Compact { basis: BASIS, size: SIZE, margin: MARGIN };
+
+            let verbose_json = serde_json::to_string(&verbose).unwrap();
+            let compact_json = serde_json::to_string(&compact).unwrap();
+            assert!(compact_json.len() < verbose_json.len());
+
+            let verbose: Verbose = serde_json::from_str(&verbose_json).unwrap();
+            let compact: Compact = serde_json::from_str(&compact_json).unwrap();
+            assert_eq!(verbose.basis, compact.basis);
+            assert_eq!(verbose.size, compact.size);
+            assert_eq!(verbose.margin, compact.margin);
+        }
+
+        #[test]
+        fn compact_rejects_invalid_strings() {
+            assert!(serde_json::from_str::<Compact>(
+                r#"{"basis":"10","size":{"width":"auto","height":"auto"},"margin":{}}"#
+            )
+            .is_err());
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    mod test_skip_default {
+        use crate::style::*;
+
+        #[test]
+        fn default_style_serializes_to_an_empty_object() {
+            let json = serde_json::to_string(&FlexboxLayout::default()).unwrap();
+            assert_eq!(json, "{}");
+            assert_eq!(serde_json::from_str::<FlexboxLayout>(&json).unwrap(), FlexboxLayout::default());
+        }
+
+        #[test]
+        fn only_fields_that_differ_from_the_default_are_serialized() {
+            let style = FlexboxLayout {
+                flex_direction: FlexDirection::Column,
+                flex_shrink: 0.0,
+                size: Size { width: Dimension::Points(10.0), height: Dimension::Undefined },
+                ..Default::default()
+            };
+            let json = serde_json::to_string(&style).unwrap();
+
+            assert_eq!(
+                json,
+                r#"{"flex_direction":"Column","flex_shrink":0.0,"size":{"width":{"Points":10.0},"height":"Undefined"}}"#
+            );
+            assert_eq!(serde_json::from_str::<FlexboxLayout>(&json).unwrap(), style);
+        }
+    }
+
+    mod test_style_diff {
+        use crate::style::*;
+        use std::string::ToString;
+
+        #[test]
+        fn identical_styles_have_no_diff() {
+            let style = FlexboxLayout { flex_grow: 2.0, ..Default::default() };
+            let diff = style.diff(&style);
+            assert!(diff.is_empty());
+            assert_eq!(diff.len(), 0);
+            assert_eq!(diff.to_string(), "");
+        }
+
+        #[test]
+        fn diff_lists_exactly_the_changed_fields() {
+            let old = FlexboxLayout::default();
+            let new = FlexboxLayout {
+                padding: Rect::from_points(1.0, 1.0, 1.0, 1.0),
+                flex_grow: 1.0,
+                aspect_ratio: Some(2.0),
+                ..Default::default()
+            };
+            let diff = old.diff(&new);
+
+            assert_eq!(diff.len(), 3);
+            assert!(diff.contains(StyleField::Padding));
+            assert!(diff.contains(StyleField::FlexGrow));
+            assert!(diff.contains(StyleField::AspectRatio));
+            assert!(!diff.contains(StyleField::Margin));
+            assert!(diff.iter().eq([StyleField::Padding, StyleField::FlexGrow, StyleField::AspectRatio]));
+            assert_eq!(diff.to_string(), "padding, flex_grow, aspect_ratio");
+            assert_eq!(new.diff(&old), diff);
+        }
+
+        #[test]
+        fn every_field_can_be_reported() {
+            let old = FlexboxLayout::default();
+            let new = FlexboxLayout {
+                display: Display::None,
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
+                flex_wrap: FlexWrap::Wrap,
+                align_items: AlignItems::Center,
+                align_self: AlignSelf::Center,
+                align_content: AlignContent::Center,
+                justify_content: JustifyContent::Center,
+                position: Rect::from_points(1.0, 1.0, 1.0, 1.0),
+                margin: Rect::from_points(1.0, 1.0, 1.0, 1.0),
+                padding: Rect::from_points(1.0, 1.0, 1.0, 1.0),
+                border: Rect::from_points(1.0, 1.0, 1.0, 1.0),
+                flex_grow: 1.0,
+                flex_shrink: 0.0,
+                flex_basis: Dimension::Points(1.0),
+                size: Size::from_points(1.0, 1.0),
+                min_size: Size::from_points(1.0, 1.0),
+                max_size: Size::from_points(1.0, 1.0),
+                aspect_ratio: Some(1.0),
+                collapse_margins: true,
+                order: 1,
+                line_min_cross_size: Dimension::Points(1.0),
+                gap: Size::from_points(1.0, 1.0),
+            };
+
+            assert!(old.diff(&new).iter().eq(StyleField::ALL));
         }
     }
 
@@ -581,6 +1749,64 @@ mod tests {
             FlexboxLayout { align_self: align, ..Default::default() }
         }
 
+        #[test]
+        fn flexbox_layout_approx_eq() {
+            let layout = FlexboxLayout {
+                size: Size::from_points(10.0, 20.0),
+                margin: Rect::from_percent(0.1, 0.1, 0.1, 0.1),
+                flex_grow: 1.0,
+                aspect_ratio: Some(2.0),
+                ..Default::default()
+            };
+            let nudged = FlexboxLayout {
+                size: Size::from_points(10.0 + 1e-6, 20.0),
+                margin: Rect::from_percent(0.1 + 1e-7, 0.1, 0.1, 0.1),
+                flex_grow: 1.0 - 1e-7,
+                aspect_ratio: Some(2.0 + 1e-6),
+                ..layout
+            };
+
+            assert_ne!(layout, nudged);
+            assert!(layout.approx_eq(&nudged, 1e-5));
+            assert!(!layout.approx_eq(&nudged, 1e-8));
+            assert!(!layout.approx_eq(&FlexboxLayout { flex_wrap: FlexWrap::Wrap, ..layout }, 1e-5));
+            assert!(!layout.approx_eq(&FlexboxLayout { aspect_ratio: None, ..layout }, 1e-5));
+        }
+
+        #[test]
+        fn dimension_approx_eq() {
+            assert!(Dimension::Points(1.0).approx_eq(&Dimension::Points(1.0 + 1e-7), 1e-6));
+            assert!(Dimension::Percent(0.5).approx_eq(&Dimension::Percent(0.5 - 1e-7), 1e-6));
+            assert!(!Dimension::Points(1.0).approx_eq(&Dimension::Points(1.1), 1e-6));
+            assert!(!Dimension::Points(0.5).approx_eq(&Dimension::Percent(0.5), 1e-6));
+            assert!(Dimension::Auto.approx_eq(&Dimension::Auto, 0.0));
+            assert!(!Dimension::Auto.approx_eq(&Dimension::Undefined, 1.0));
+        }
+
+        #[test]
+        fn flexbox_layout_axis_combinators() {
+            let layout = FlexboxLayout::row().wrap().reverse();
+            assert_eq!(layout.flex_direction, FlexDirection::RowReverse);
+            assert_eq!(layout.flex_wrap, FlexWrap::Wrap);
+            assert_eq!(layout.reverse().flex_direction, FlexDirection::Row);
+
+            let layout = FlexboxLayout::column().wrap_reverse();
+            assert_eq!(layout.flex_direction, FlexDirection::Column);
+            assert_eq!(layout.flex_wrap, FlexWrap::WrapReverse);
+            assert_eq!(layout.reverse().flex_direction, FlexDirection::ColumnReverse);
+            assert_eq!(layout.nowrap().flex_wrap, FlexWrap::NoWrap);
+        }
+
+        #[test]
+        fn flexbox_layout_aspect_ratio_combinators() {
+            let layout = FlexboxLayout::row().with_aspect_ratio(1.5);
+            assert_eq!(layout.aspect_ratio, Some(1.5));
+            assert_eq!(layout.flex_direction, FlexDirection::Row);
+            assert_eq!(layout.with_aspect_ratio(2.0).aspect_ratio, Some(2.0));
+            assert_eq!(layout.without_aspect_ratio().aspect_ratio, None);
+            assert_eq!(layout.without_aspect_ratio().with_aspect_ratio(1.5), layout);
+        }
+
         #[test]
         fn flexbox_layout_min_main_size() {
             let layout = FlexboxLayout { min_size: Size::from_points(1.0, 2.0), ..Default::default() };
@@ -689,5 +1915,36 @@ mod tests {
             let layout = layout_from_align_self(AlignSelf::Stretch);
             assert_eq!(layout.align_self(&parent), AlignSelf::Stretch);
         }
+
+        #[test]
+        fn scaled_multiplies_points() {
+            let layout = FlexboxLayout {
+                size: Size::from_points(10.0, 20.0),
+                margin: Rect::from_points(1.0, 2.0, 3.0, 4.0),
+                flex_basis: Dimension::Points(5.0),
+                ..Default::default()
+            }
+            .scaled(2.0);
+
+            assert_eq!(layout.size, Size::from_points(20.0, 40.0));
+            assert_eq!(layout.margin, Rect::from_points(2.0, 4.0, 6.0, 8.0));
+            assert_eq!(layout.flex_basis, Dimension::Points(10.0));
+        }
+
+        #[test]
+        fn scaled_leaves_other_dimensions_untouched() {
+            let layout = FlexboxLayout {
+                size: Size { width: Dimension::Percent(0.5), height: Dimension::Auto },
+                padding: Rect::from_percent(0.1, 0.2, 0.3, 0.4),
+                flex_grow: 1.0,
+                ..Default::default()
+            };
+            let scaled = layout.scaled(2.0);
+
+            assert_eq!(scaled.size, layout.size);
+            assert_eq!(scaled.padding, layout.padding);
+            assert_eq!(scaled.max_size, layout.max_size);
+            assert_eq!(scaled.flex_grow, 1.0);
+        }
     }
 }
@@ -47,6 +47,18 @@ mod std {
         value.round()
     }
 
+    /// Rounds down to the nearest whole number
+    #[must_use]
+    pub(crate) fn floor(value: f32) -> f32 {
+        value.floor()
+    }
+
+    /// Rounds up to the nearest whole number
+    #[must_use]
+    pub(crate) fn ceil(value: f32) -> f32 {
+        value.ceil()
+    }
+
     /// Computes the absolute value
     #[must_use]
     pub(crate) fn abs(value: f32) -> f32 {
@@ -88,6 +100,18 @@ mod alloc {
         num_traits::float::FloatCore::round(value)
     }
 
+    /// Rounds down to the nearest whole number
+    #[must_use]
+    pub(crate) fn floor(value: f32) -> f32 {
+        num_traits::float::FloatCore::floor(value)
+    }
+
+    /// Rounds up to the nearest whole number
+    #[must_use]
+    pub(crate) fn ceil(value: f32) -> f32 {
+        num_traits::float::FloatCore::ceil(value)
+    }
+
     /// Computes the absolute value
     #[must_use]
     pub(crate) fn abs(value: f32) -> f32 {
@@ -140,6 +164,18 @@ mod core {
         num_traits::float::FloatCore::round(value)
     }
 
+    /// Rounds down to the nearest whole number
+    #[must_use]
+    pub(crate) fn floor(value: f32) -> f32 {
+        num_traits::float::FloatCore::floor(value)
+    }
+
+    /// Rounds up to the nearest whole number
+    #[must_use]
+    pub(crate) fn ceil(value: f32) -> f32 {
+        num_traits::float::FloatCore::ceil(value)
+    }
+
     /// Computes the absolute value
     #[inline]
     #[must_use]
@@ -1,3 +1,6 @@
+// The generated fixtures always emit `..Default::default()`, even when every field is specified
+#![allow(clippy::needless_update)]
+
 // this declaration is necessary to "mount" the generated code where cargo can see it
 // this allows us to both keep code generation scoped to a singe directory for fs events
 // and to keep each test in a separate file
@@ -1,6 +1,7 @@
 #[test]
 fn absolute_layout_align_items_and_justify_content_flex_end() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node0 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
@@ -1,6 +1,7 @@
 #[test]
 fn absolute_layout_percentage_bottom_based_on_parent_height() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node0 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
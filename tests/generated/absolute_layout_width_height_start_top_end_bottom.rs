@@ -1,6 +1,7 @@
 #[test]
 fn absolute_layout_width_height_start_top_end_bottom() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node0 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
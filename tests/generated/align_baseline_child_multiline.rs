@@ -1,6 +1,7 @@
 #[test]
 fn align_baseline_child_multiline() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node0 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
@@ -1,6 +1,7 @@
 #[test]
 fn align_center_should_size_based_on_content() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node000 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
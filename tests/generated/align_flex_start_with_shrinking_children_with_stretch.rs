@@ -1,6 +1,7 @@
 #[test]
 fn align_flex_start_with_shrinking_children_with_stretch() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node000 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout { flex_grow: 1f32, flex_shrink: 1f32, ..Default::default() },
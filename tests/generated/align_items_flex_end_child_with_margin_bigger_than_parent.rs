@@ -1,6 +1,7 @@
 #[test]
 fn align_items_flex_end_child_with_margin_bigger_than_parent() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node00 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
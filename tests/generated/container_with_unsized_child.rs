@@ -1,6 +1,7 @@
 #[test]
 fn container_with_unsized_child() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node0 = taffy.new_with_children(taffy::style::FlexboxLayout { ..Default::default() }, &[]).unwrap();
     let node = taffy
         .new_with_children(
@@ -1,6 +1,7 @@
 #[test]
 fn display_none_with_position() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node0 =
         taffy.new_with_children(taffy::style::FlexboxLayout { flex_grow: 1f32, ..Default::default() }, &[]).unwrap();
     let node1 = taffy
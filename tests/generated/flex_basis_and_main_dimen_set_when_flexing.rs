@@ -1,6 +1,7 @@
 #[test]
 fn flex_basis_and_main_dimen_set_when_flexing() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node0 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
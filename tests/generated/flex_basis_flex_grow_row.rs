@@ -1,6 +1,7 @@
 #[test]
 fn flex_basis_flex_grow_row() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node0 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
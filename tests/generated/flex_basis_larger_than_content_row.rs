@@ -1,6 +1,7 @@
 #[test]
 fn flex_basis_larger_than_content_row() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node00 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
@@ -1,6 +1,7 @@
 #[test]
 fn flex_basis_smaller_than_content_column() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node00 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
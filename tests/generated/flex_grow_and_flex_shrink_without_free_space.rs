@@ -0,0 +1,68 @@
+#[test]
+fn flex_grow_and_flex_shrink_without_free_space() {
+    let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
+    let node0 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                flex_grow: 1f32,
+                flex_shrink: 1f32,
+                flex_basis: taffy::style::Dimension::Points(50f32),
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node1 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                flex_grow: 2f32,
+                flex_shrink: 0f32,
+                flex_basis: taffy::style::Dimension::Points(50f32),
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node2 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                flex_grow: 0f32,
+                flex_shrink: 2f32,
+                flex_basis: taffy::style::Dimension::Points(50f32),
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(150f32),
+                    height: taffy::style::Dimension::Points(10f32),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[node0, node1, node2],
+        )
+        .unwrap();
+    taffy.compute_layout(node, taffy::geometry::Size::undefined()).unwrap();
+    assert_eq!(taffy.layout(node).unwrap().size.width, 150f32);
+    assert_eq!(taffy.layout(node).unwrap().size.height, 10f32);
+    assert_eq!(taffy.layout(node).unwrap().location.x, 0f32);
+    assert_eq!(taffy.layout(node).unwrap().location.y, 0f32);
+    assert_eq!(taffy.layout(node0).unwrap().size.width, 50f32);
+    assert_eq!(taffy.layout(node0).unwrap().size.height, 10f32);
+    assert_eq!(taffy.layout(node0).unwrap().location.x, 0f32);
+    assert_eq!(taffy.layout(node0).unwrap().location.y, 0f32);
+    assert_eq!(taffy.layout(node1).unwrap().size.width, 50f32);
+    assert_eq!(taffy.layout(node1).unwrap().size.height, 10f32);
+    assert_eq!(taffy.layout(node1).unwrap().location.x, 50f32);
+    assert_eq!(taffy.layout(node1).unwrap().location.y, 0f32);
+    assert_eq!(taffy.layout(node2).unwrap().size.width, 50f32);
+    assert_eq!(taffy.layout(node2).unwrap().size.height, 10f32);
+    assert_eq!(taffy.layout(node2).unwrap().location.x, 100f32);
+    assert_eq!(taffy.layout(node2).unwrap().location.y, 0f32);
+}
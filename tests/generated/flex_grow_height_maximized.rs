@@ -1,6 +1,7 @@
 #[test]
 fn flex_grow_height_maximized() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node00 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
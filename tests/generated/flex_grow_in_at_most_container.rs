@@ -1,6 +1,7 @@
 #[test]
 fn flex_grow_in_at_most_container() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node00 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
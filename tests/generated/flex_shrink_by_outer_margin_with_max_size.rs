@@ -1,6 +1,7 @@
 #[test]
 fn flex_shrink_by_outer_margin_with_max_size() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node0 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
@@ -1,6 +1,7 @@
 #[test]
 fn flex_shrink_flex_grow_child_flex_shrink_other_child() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node0 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
@@ -1,6 +1,7 @@
 #[test]
 fn flex_wrap_children_with_min_main_overriding_flex_basis() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node0 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
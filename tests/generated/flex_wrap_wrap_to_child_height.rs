@@ -1,6 +1,7 @@
 #[test]
 fn flex_wrap_wrap_to_child_height() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node000 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
@@ -0,0 +1,116 @@
+#[test]
+fn gap_wrap_row_flex_end() {
+    let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
+    let node0 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(30f32),
+                    height: taffy::style::Dimension::Points(20f32),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node1 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(30f32),
+                    height: taffy::style::Dimension::Points(20f32),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node2 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(30f32),
+                    height: taffy::style::Dimension::Points(20f32),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node3 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(30f32),
+                    height: taffy::style::Dimension::Points(20f32),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node4 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(30f32),
+                    height: taffy::style::Dimension::Points(20f32),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                flex_wrap: taffy::style::FlexWrap::Wrap,
+                align_content: taffy::style::AlignContent::FlexEnd,
+                justify_content: taffy::style::JustifyContent::FlexEnd,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(100f32),
+                    height: taffy::style::Dimension::Points(100f32),
+                    ..Default::default()
+                },
+                gap: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(10f32),
+                    height: taffy::style::Dimension::Points(5f32),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[node0, node1, node2, node3, node4],
+        )
+        .unwrap();
+    taffy.compute_layout(node, taffy::geometry::Size::undefined()).unwrap();
+    assert_eq!(taffy.layout(node).unwrap().size.width, 100f32);
+    assert_eq!(taffy.layout(node).unwrap().size.height, 100f32);
+    assert_eq!(taffy.layout(node).unwrap().location.x, 0f32);
+    assert_eq!(taffy.layout(node).unwrap().location.y, 0f32);
+    assert_eq!(taffy.layout(node0).unwrap().size.width, 30f32);
+    assert_eq!(taffy.layout(node0).unwrap().size.height, 20f32);
+    assert_eq!(taffy.layout(node0).unwrap().location.x, 30f32);
+    assert_eq!(taffy.layout(node0).unwrap().location.y, 30f32);
+    assert_eq!(taffy.layout(node1).unwrap().size.width, 30f32);
+    assert_eq!(taffy.layout(node1).unwrap().size.height, 20f32);
+    assert_eq!(taffy.layout(node1).unwrap().location.x, 70f32);
+    assert_eq!(taffy.layout(node1).unwrap().location.y, 30f32);
+    assert_eq!(taffy.layout(node2).unwrap().size.width, 30f32);
+    assert_eq!(taffy.layout(node2).unwrap().size.height, 20f32);
+    assert_eq!(taffy.layout(node2).unwrap().location.x, 30f32);
+    assert_eq!(taffy.layout(node2).unwrap().location.y, 55f32);
+    assert_eq!(taffy.layout(node3).unwrap().size.width, 30f32);
+    assert_eq!(taffy.layout(node3).unwrap().size.height, 20f32);
+    assert_eq!(taffy.layout(node3).unwrap().location.x, 70f32);
+    assert_eq!(taffy.layout(node3).unwrap().location.y, 55f32);
+    assert_eq!(taffy.layout(node4).unwrap().size.width, 30f32);
+    assert_eq!(taffy.layout(node4).unwrap().size.height, 20f32);
+    assert_eq!(taffy.layout(node4).unwrap().location.x, 70f32);
+    assert_eq!(taffy.layout(node4).unwrap().location.y, 80f32);
+}
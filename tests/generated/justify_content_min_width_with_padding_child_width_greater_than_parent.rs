@@ -1,6 +1,7 @@
 #[test]
 fn justify_content_min_width_with_padding_child_width_greater_than_parent() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node000 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
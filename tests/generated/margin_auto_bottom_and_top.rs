@@ -1,6 +1,7 @@
 #[test]
 fn margin_auto_bottom_and_top() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node0 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
@@ -1,6 +1,7 @@
 #[test]
 fn margin_fix_left_auto_right_child_bigger_than_parent() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node0 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
@@ -1,6 +1,7 @@
 #[test]
 fn max_height_overrides_height_on_root() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
@@ -1,6 +1,7 @@
 #[test]
 fn min_width_overrides_width_on_root() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
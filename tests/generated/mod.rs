@@ -7,6 +7,7 @@ mod absolute_layout_align_items_and_justify_content_flex_end;
 mod absolute_layout_align_items_center;
 mod absolute_layout_align_items_center_on_child_only;
 mod absolute_layout_child_order;
+mod absolute_layout_children_within_inset_box;
 mod absolute_layout_in_wrap_reverse_column_container;
 mod absolute_layout_in_wrap_reverse_column_container_flex_end;
 mod absolute_layout_in_wrap_reverse_row_container;
@@ -27,6 +28,7 @@ mod align_flex_start_with_shrinking_children;
 mod align_flex_start_with_shrinking_children_with_stretch;
 mod align_flex_start_with_stretching_children;
 mod align_items_center;
+mod align_items_center_child_taller_than_container;
 mod align_items_center_child_with_margin_bigger_than_parent;
 mod align_items_center_child_without_margin_bigger_than_parent;
 mod align_items_center_with_child_margin;
@@ -37,12 +39,15 @@ mod align_items_flex_end_child_without_margin_bigger_than_parent;
 mod align_items_flex_start;
 mod align_items_min_max;
 mod align_items_stretch;
+mod align_items_stretch_with_definite_cross_size;
 mod align_self_baseline;
 mod align_self_center;
+mod align_self_center_child_taller_than_container;
 mod align_self_flex_end;
 mod align_self_flex_end_override_flex_start;
 mod align_self_flex_start;
 mod align_strech_should_size_based_on_parent;
+mod aspect_ratio_flex_basis_from_percentage_height;
 mod border_center_child;
 mod border_flex_child;
 mod border_no_child;
@@ -79,6 +84,7 @@ mod flex_direction_column_reverse;
 mod flex_direction_row;
 mod flex_direction_row_no_width;
 mod flex_direction_row_reverse;
+mod flex_grow_and_flex_shrink_without_free_space;
 mod flex_grow_child;
 mod flex_grow_flex_basis_percent_min_max;
 mod flex_grow_height_maximized;
@@ -87,6 +93,7 @@ mod flex_grow_less_than_factor_one;
 mod flex_grow_root_minimized;
 mod flex_grow_shrink_at_most;
 mod flex_grow_to_min;
+mod flex_grow_with_positive_free_space_ignores_flex_shrink;
 mod flex_grow_within_constrained_max_column;
 mod flex_grow_within_constrained_max_row;
 mod flex_grow_within_constrained_max_width;
@@ -99,9 +106,14 @@ mod flex_shrink_by_outer_margin_with_max_size;
 mod flex_shrink_flex_grow_child_flex_shrink_other_child;
 mod flex_shrink_flex_grow_row;
 mod flex_shrink_to_zero;
+mod flex_shrink_with_negative_free_space_ignores_flex_grow;
+mod flex_shrink_zero_keeps_the_base_size_overflowing;
+mod flex_shrink_zero_keeps_the_base_size_partly_absorbed;
 mod flex_wrap_align_stretch_fits_one_row;
 mod flex_wrap_children_with_min_main_overriding_flex_basis;
 mod flex_wrap_wrap_to_child_height;
+mod gap_wrap_row_flex_end;
+mod gap_wrap_row_flex_start;
 mod justify_content_column_center;
 mod justify_content_column_flex_end;
 mod justify_content_column_flex_start;
@@ -122,6 +134,7 @@ mod justify_content_row_min_width_and_margin;
 mod justify_content_row_space_around;
 mod justify_content_row_space_between;
 mod justify_content_row_space_evenly;
+mod justify_content_space_between_with_non_growing_items;
 mod margin_and_flex_column;
 mod margin_and_flex_row;
 mod margin_and_stretch_column;
@@ -138,6 +151,7 @@ mod margin_auto_left_child_bigger_than_parent;
 mod margin_auto_left_fix_right_child_bigger_than_parent;
 mod margin_auto_left_right_child_bigger_than_parent;
 mod margin_auto_left_stretching_child;
+mod margin_auto_left_with_justify_content_center;
 mod margin_auto_mutiple_children_column;
 mod margin_auto_mutiple_children_row;
 mod margin_auto_right;
@@ -157,6 +171,8 @@ mod max_height;
 mod max_height_overrides_height;
 mod max_height_overrides_height_on_root;
 mod max_width;
+mod max_width_clamps_auto_width_nested;
+mod max_width_clamps_auto_width_root;
 mod max_width_overrides_width;
 mod max_width_overrides_width_on_root;
 mod min_height;
@@ -168,6 +184,7 @@ mod min_width_overrides_width;
 mod min_width_overrides_width_on_root;
 mod nested_overflowing_child;
 mod nested_overflowing_child_in_constraint_parent;
+mod order_equal_values_keep_source_order;
 mod overflow_cross_axis;
 mod overflow_main_axis;
 mod padding_align_end_child;
@@ -209,6 +226,9 @@ mod rounding_fractial_input_3;
 mod rounding_fractial_input_4;
 mod rounding_total_fractial;
 mod rounding_total_fractial_nested;
+mod row_auto_width_is_the_sum_of_the_items_plain;
+mod row_auto_width_is_the_sum_of_the_items_with_gaps;
+mod row_auto_width_is_the_sum_of_the_items_with_margins;
 mod size_defined_by_child;
 mod size_defined_by_child_with_border;
 mod size_defined_by_child_with_padding;
@@ -229,10 +249,20 @@ mod wrap_reverse_row_align_content_space_around;
 mod wrap_reverse_row_align_content_stretch;
 mod wrap_reverse_row_single_line_different_size;
 mod wrap_row;
+mod wrap_row_align_content_center;
+mod wrap_row_align_content_flex_end;
+mod wrap_row_align_content_flex_start;
+mod wrap_row_align_content_space_around;
+mod wrap_row_align_content_space_between;
+mod wrap_row_align_content_stretch;
 mod wrap_row_align_items_center;
 mod wrap_row_align_items_flex_end;
+mod wrap_row_auto_height_is_the_sum_of_the_lines;
+mod wrap_row_oversized_item_on_its_own_line;
+mod wrap_row_reverse_reverses_items_within_each_line;
 mod wrapped_column_max_height;
 mod wrapped_column_max_height_flex;
 mod wrapped_row_within_align_items_center;
 mod wrapped_row_within_align_items_flex_end;
 mod wrapped_row_within_align_items_flex_start;
+mod zero_size_container_with_overflowing_centered_children;
@@ -0,0 +1,142 @@
+#[test]
+fn order_equal_values_keep_source_order() {
+    let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
+    let node0 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(10f32),
+                    height: taffy::style::Dimension::Points(10f32),
+                    ..Default::default()
+                },
+                order: 0i32,
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node1 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(10f32),
+                    height: taffy::style::Dimension::Points(10f32),
+                    ..Default::default()
+                },
+                order: 1i32,
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node2 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(10f32),
+                    height: taffy::style::Dimension::Points(10f32),
+                    ..Default::default()
+                },
+                order: -1i32,
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node3 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(10f32),
+                    height: taffy::style::Dimension::Points(10f32),
+                    ..Default::default()
+                },
+                order: 0i32,
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node4 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(10f32),
+                    height: taffy::style::Dimension::Points(10f32),
+                    ..Default::default()
+                },
+                order: 1i32,
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node5 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(10f32),
+                    height: taffy::style::Dimension::Points(10f32),
+                    ..Default::default()
+                },
+                order: -1i32,
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node6 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(10f32),
+                    height: taffy::style::Dimension::Points(10f32),
+                    ..Default::default()
+                },
+                order: 0i32,
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout { ..Default::default() },
+            &[node0, node1, node2, node3, node4, node5, node6],
+        )
+        .unwrap();
+    taffy.compute_layout(node, taffy::geometry::Size::undefined()).unwrap();
+    assert_eq!(taffy.layout(node).unwrap().size.width, 70f32);
+    assert_eq!(taffy.layout(node).unwrap().size.height, 10f32);
+    assert_eq!(taffy.layout(node).unwrap().location.x, 0f32);
+    assert_eq!(taffy.layout(node).unwrap().location.y, 0f32);
+    assert_eq!(taffy.layout(node0).unwrap().size.width, 10f32);
+    assert_eq!(taffy.layout(node0).unwrap().size.height, 10f32);
+    assert_eq!(taffy.layout(node0).unwrap().location.x, 20f32);
+    assert_eq!(taffy.layout(node0).unwrap().location.y, 0f32);
+    assert_eq!(taffy.layout(node1).unwrap().size.width, 10f32);
+    assert_eq!(taffy.layout(node1).unwrap().size.height, 10f32);
+    assert_eq!(taffy.layout(node1).unwrap().location.x, 50f32);
+    assert_eq!(taffy.layout(node1).unwrap().location.y, 0f32);
+    assert_eq!(taffy.layout(node2).unwrap().size.width, 10f32);
+    assert_eq!(taffy.layout(node2).unwrap().size.height, 10f32);
+    assert_eq!(taffy.layout(node2).unwrap().location.x, 0f32);
+    assert_eq!(taffy.layout(node2).unwrap().location.y, 0f32);
+    assert_eq!(taffy.layout(node3).unwrap().size.width, 10f32);
+    assert_eq!(taffy.layout(node3).unwrap().size.height, 10f32);
+    assert_eq!(taffy.layout(node3).unwrap().location.x, 30f32);
+    assert_eq!(taffy.layout(node3).unwrap().location.y, 0f32);
+    assert_eq!(taffy.layout(node4).unwrap().size.width, 10f32);
+    assert_eq!(taffy.layout(node4).unwrap().size.height, 10f32);
+    assert_eq!(taffy.layout(node4).unwrap().location.x, 60f32);
+    assert_eq!(taffy.layout(node4).unwrap().location.y, 0f32);
+    assert_eq!(taffy.layout(node5).unwrap().size.width, 10f32);
+    assert_eq!(taffy.layout(node5).unwrap().size.height, 10f32);
+    assert_eq!(taffy.layout(node5).unwrap().location.x, 10f32);
+    assert_eq!(taffy.layout(node5).unwrap().location.y, 0f32);
+    assert_eq!(taffy.layout(node6).unwrap().size.width, 10f32);
+    assert_eq!(taffy.layout(node6).unwrap().size.height, 10f32);
+    assert_eq!(taffy.layout(node6).unwrap().location.x, 40f32);
+    assert_eq!(taffy.layout(node6).unwrap().location.y, 0f32);
+}
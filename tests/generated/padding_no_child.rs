@@ -1,6 +1,7 @@
 #[test]
 fn padding_no_child() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
@@ -1,6 +1,7 @@
 #[test]
 fn parent_wrap_child_size_overflowing_parent() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node00 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
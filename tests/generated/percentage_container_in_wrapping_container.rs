@@ -1,6 +1,7 @@
 #[test]
 fn percentage_container_in_wrapping_container() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node000 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
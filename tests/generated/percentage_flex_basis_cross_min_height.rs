@@ -1,6 +1,7 @@
 #[test]
 fn percentage_flex_basis_cross_min_height() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node0 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
@@ -1,6 +1,7 @@
 #[test]
 fn percentage_margin_should_calculate_based_only_on_width() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node00 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
@@ -1,6 +1,7 @@
 #[test]
 fn percentage_multiple_nested_with_padding_margin_and_percentage_values() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node000 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
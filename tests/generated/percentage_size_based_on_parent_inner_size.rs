@@ -1,6 +1,7 @@
 #[test]
 fn percentage_size_based_on_parent_inner_size() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node0 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
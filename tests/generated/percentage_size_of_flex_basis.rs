@@ -1,6 +1,7 @@
 #[test]
 fn percentage_size_of_flex_basis() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node00 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
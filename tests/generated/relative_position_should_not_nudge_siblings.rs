@@ -1,6 +1,7 @@
 #[test]
 fn relative_position_should_not_nudge_siblings() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node0 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
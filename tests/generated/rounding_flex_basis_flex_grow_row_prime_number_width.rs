@@ -1,6 +1,7 @@
 #[test]
 fn rounding_flex_basis_flex_grow_row_prime_number_width() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node0 =
         taffy.new_with_children(taffy::style::FlexboxLayout { flex_grow: 1f32, ..Default::default() }, &[]).unwrap();
     let node1 =
@@ -1,6 +1,7 @@
 #[test]
 fn rounding_flex_basis_overrides_main_size() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node0 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
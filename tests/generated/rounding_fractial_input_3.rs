@@ -1,6 +1,7 @@
 #[test]
 fn rounding_fractial_input_3() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node0 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
@@ -1,6 +1,7 @@
 #[test]
 fn rounding_total_fractial_nested() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node00 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
@@ -0,0 +1,69 @@
+#[test]
+fn row_auto_width_is_the_sum_of_the_items_plain() {
+    let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
+    let node00 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(50f32),
+                    height: taffy::style::Dimension::Points(10f32),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node01 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(50f32),
+                    height: taffy::style::Dimension::Points(10f32),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node02 = taffy
+        .new_with_children(
+            taffy::style::FlexboxLayout {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(50f32),
+                    height: taffy::style::Dimension::Points(10f32),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[],
+        )
+        .unwrap();
+    let node0 = taffy
+        .new_with_children(taffy::style::FlexboxLayout { ..Default::default() }, &[node00, node01, node02])
+        .unwrap();
+    let node = taffy.new_with_children(taffy::style::FlexboxLayout { ..Default::default() }, &[node0]).unwrap();
+    taffy.compute_layout(node, taffy::geometry::Size::undefined()).unwrap();
+    assert_eq!(taffy.layout(node).unwrap().size.width, 150f32);
+    assert_eq!(taffy.layout(node).unwrap().size.height, 10f32);
+    assert_eq!(taffy.layout(node).unwrap().location.x, 0f32);
+    assert_eq!(taffy.layout(node).unwrap().location.y, 0f32);
+    assert_eq!(taffy.layout(node0).unwrap().size.width, 150f32);
+    assert_eq!(taffy.layout(node0).unwrap().size.height, 10f32);
+    assert_eq!(taffy.layout(node0).unwrap().location.x, 0f32);
+    assert_eq!(taffy.layout(node0).unwrap().location.y, 0f32);
+    assert_eq!(taffy.layout(node00).unwrap().size.width, 50f32);
+    assert_eq!(taffy.layout(node00).unwrap().size.height, 10f32);
+    assert_eq!(taffy.layout(node00).unwrap().location.x, 0f32);
+    assert_eq!(taffy.layout(node00).unwrap().location.y, 0f32);
+    assert_eq!(taffy.layout(node01).unwrap().size.width, 50f32);
+    assert_eq!(taffy.layout(node01).unwrap().size.height, 10f32);
+    assert_eq!(taffy.layout(node01).unwrap().location.x, 50f32);
+    assert_eq!(taffy.layout(node01).unwrap().location.y, 0f32);
+    assert_eq!(taffy.layout(node02).unwrap().size.width, 50f32);
+    assert_eq!(taffy.layout(node02).unwrap().size.height, 10f32);
+    assert_eq!(taffy.layout(node02).unwrap().location.x, 100f32);
+    assert_eq!(taffy.layout(node02).unwrap().location.y, 0f32);
+}
@@ -1,6 +1,7 @@
 #[test]
 fn size_defined_by_grand_child() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node00 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
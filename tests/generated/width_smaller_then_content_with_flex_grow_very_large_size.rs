@@ -1,6 +1,7 @@
 #[test]
 fn width_smaller_then_content_with_flex_grow_very_large_size() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node00 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
@@ -1,6 +1,7 @@
 #[test]
 fn wrap_nodes_with_content_sizing_margin_cross() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node000 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
@@ -1,6 +1,7 @@
 #[test]
 fn wrapped_row_within_align_items_flex_start() {
     let mut taffy = taffy::Taffy::new();
+    taffy.enable_rounding();
     let node00 = taffy
         .new_with_children(
             taffy::style::FlexboxLayout {
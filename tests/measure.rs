@@ -520,6 +520,223 @@ mod measure {
         let node = taffy.new_with_children(taffy::style::FlexboxLayout { ..Default::default() }, &[child]).unwrap();
         taffy.compute_layout(node, taffy::geometry::Size::undefined()).unwrap();
 
-        assert_eq!(NUM_MEASURES.load(atomic::Ordering::Relaxed), 2);
+        assert_eq!(NUM_MEASURES.load(atomic::Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn measure_results_are_cached_per_constraint_mode() {
+        use std::sync::atomic;
+
+        let mut taffy = taffy::node::Taffy::new();
+        static NUM_MEASURES: atomic::AtomicU32 = atomic::AtomicU32::new(0);
+
+        let child = taffy
+            .new_leaf_with_measure(
+                taffy::style::FlexboxLayout { flex_grow: 1.0, ..Default::default() },
+                MeasureFunc::Raw(|constraint| {
+                    NUM_MEASURES.fetch_add(1, atomic::Ordering::Relaxed);
+                    taffy::geometry::Size {
+                        width: constraint.width.unwrap_or(50.0),
+                        height: constraint.height.unwrap_or(50.0),
+                    }
+                }),
+            )
+            .unwrap();
+
+        let node = taffy
+            .new_with_children(
+                taffy::style::FlexboxLayout {
+                    size: taffy::geometry::Size {
+                        width: taffy::style::Dimension::Points(100.0),
+                        height: taffy::style::Dimension::Points(100.0),
+                    },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        taffy.compute_layout(node, taffy::geometry::Size::undefined()).unwrap();
+        let first_layout_measures = NUM_MEASURES.load(atomic::Ordering::Relaxed);
+
+        // The child is still clean, so the results of each of its measurements can be reused
+        taffy.mark_dirty(node).unwrap();
+        taffy.compute_layout(node, taffy::geometry::Size::undefined()).unwrap();
+
+        assert_eq!(NUM_MEASURES.load(atomic::Ordering::Relaxed), first_layout_measures);
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn measure_identical_constraints_once_per_layout() {
+        use std::sync::{Arc, Mutex};
+
+        let mut taffy = taffy::node::Taffy::new();
+        let constraints = Arc::new(Mutex::new(Vec::new()));
+
+        let measure = |name: &'static str| {
+            let constraints = Arc::clone(&constraints);
+            MeasureFunc::Boxed(Box::new(move |constraint: taffy::geometry::Size<Option<f32>>| {
+                constraints.lock().unwrap().push((name, constraint));
+                taffy::geometry::Size {
+                    width: constraint.width.unwrap_or(50.0),
+                    height: constraint.height.unwrap_or(50.0),
+                }
+            }))
+        };
+        let child = taffy
+            .new_leaf_with_measure(
+                taffy::style::FlexboxLayout { flex_grow: 1.0, ..Default::default() },
+                measure("child"),
+            )
+            .unwrap();
+        let absolute_child = taffy
+            .new_leaf_with_measure(
+                taffy::style::FlexboxLayout {
+                    position_type: taffy::style::PositionType::Absolute,
+                    ..Default::default()
+                },
+                measure("absolute_child"),
+            )
+            .unwrap();
+
+        let node = taffy
+            .new_with_children(
+                taffy::style::FlexboxLayout {
+                    size: taffy::geometry::Size {
+                        width: taffy::style::Dimension::Points(100.0),
+                        height: taffy::style::Dimension::Points(100.0),
+                    },
+                    min_size: taffy::geometry::Size {
+                        width: taffy::style::Dimension::Points(50.0),
+                        height: taffy::style::Dimension::Points(50.0),
+                    },
+                    ..Default::default()
+                },
+                &[child, absolute_child],
+            )
+            .unwrap();
+        taffy.compute_layout(node, taffy::geometry::Size::undefined()).unwrap();
+
+        let constraints = constraints.lock().unwrap();
+        assert!(!constraints.is_empty());
+        for (i, constraint) in constraints.iter().enumerate() {
+            assert!(!constraints[..i].contains(constraint), "{:?} was measured more than once", constraint);
+        }
+    }
+
+    // Debug builds report the invalid measurement by panicking once the layout is complete
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "the measure function of Node#0 returned"))]
+    fn measure_non_finite_size_is_sanitized() {
+        let mut taffy = taffy::node::Taffy::new();
+        let child = taffy
+            .new_leaf_with_measure(
+                taffy::style::FlexboxLayout { ..Default::default() },
+                MeasureFunc::Raw(|_| taffy::geometry::Size { width: f32::NAN, height: f32::INFINITY }),
+            )
+            .unwrap();
+        let sibling = taffy
+            .new_leaf(taffy::style::FlexboxLayout {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(50.0),
+                    height: taffy::style::Dimension::Points(50.0),
+                },
+                ..Default::default()
+            })
+            .unwrap();
+
+        let node =
+            taffy.new_with_children(taffy::style::FlexboxLayout { ..Default::default() }, &[child, sibling]).unwrap();
+        taffy.compute_layout(node, taffy::geometry::Size::undefined()).unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().size.width, 0.0);
+        assert_eq!(taffy.layout(sibling).unwrap().location.x, 0.0);
+        assert_eq!(taffy.layout(node).unwrap().size.width, 50.0);
+        assert_eq!(taffy.layout(node).unwrap().size.height, 50.0);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "the measure function of Node#1 returned")]
+    fn measure_invalid_size_panics_in_debug_builds() {
+        let mut taffy = taffy::node::Taffy::new();
+        let sibling = taffy.new_leaf(taffy::style::FlexboxLayout { ..Default::default() }).unwrap();
+        let child = taffy
+            .new_leaf_with_measure(
+                taffy::style::FlexboxLayout { ..Default::default() },
+                MeasureFunc::Raw(|_| taffy::geometry::Size { width: -10.0, height: 10.0 }),
+            )
+            .unwrap();
+
+        let node =
+            taffy.new_with_children(taffy::style::FlexboxLayout { ..Default::default() }, &[sibling, child]).unwrap();
+        taffy.compute_layout(node, taffy::geometry::Size::undefined()).unwrap();
+    }
+
+    #[test]
+    fn measure_height_depends_on_resolved_width() {
+        let mut taffy = taffy::node::Taffy::new();
+
+        // Like wrapping text: the narrower the available width, the taller the content
+        let child = taffy
+            .new_leaf_with_measure(
+                taffy::style::FlexboxLayout { ..Default::default() },
+                MeasureFunc::Raw(|constraint| {
+                    let width = constraint.width.unwrap_or(200.0);
+                    taffy::geometry::Size { width, height: constraint.height.unwrap_or(1000.0 / width) }
+                }),
+            )
+            .unwrap();
+
+        let node = taffy
+            .new_with_children(
+                taffy::style::FlexboxLayout {
+                    size: taffy::geometry::Size {
+                        width: taffy::style::Dimension::Points(100.0),
+                        height: taffy::style::Dimension::Auto,
+                    },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+
+        taffy.compute_layout(node, taffy::geometry::Size::undefined()).unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().size.width, 100.0);
+        assert_eq!(taffy.layout(child).unwrap().size.height, 10.0);
+        assert_eq!(taffy.layout(node).unwrap().size.height, 10.0);
+    }
+
+    #[test]
+    fn measure_result_is_used_as_auto_flex_basis() {
+        let mut taffy = taffy::node::Taffy::new();
+
+        let child = taffy
+            .new_leaf_with_measure(
+                taffy::style::FlexboxLayout { flex_grow: 0.0, ..Default::default() },
+                MeasureFunc::Raw(|constraint| taffy::geometry::Size {
+                    width: constraint.width.unwrap_or(120.0),
+                    height: constraint.height.unwrap_or(20.0),
+                }),
+            )
+            .unwrap();
+
+        let node = taffy
+            .new_with_children(
+                taffy::style::FlexboxLayout {
+                    size: taffy::geometry::Size {
+                        width: taffy::style::Dimension::Points(500.0),
+                        height: taffy::style::Dimension::Points(100.0),
+                    },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+
+        taffy.compute_layout(node, taffy::geometry::Size::undefined()).unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().size.width, 120.0);
     }
 }